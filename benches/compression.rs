@@ -0,0 +1,100 @@
+//! Compares the compressors available for `ENetHost.compressor` on
+//! datagrams shaped like game state updates.
+//!
+//! Run with `cargo bench --bench compression --features lz4,zstd`.
+
+use std::time::{Duration, Instant};
+
+use enet_rs::{compressor::Compressor, context::Enet, range_coder::RangeCoder};
+
+const DATAGRAMS: usize = 2000;
+const ROUNDS: usize = 10;
+
+/** deterministic datagrams with entity ids, positions and a few flags */
+fn datagrams() -> Vec<Vec<u8>> {
+    let mut seed = 0x2545_f491_u32;
+    let mut next = move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+        seed
+    };
+
+    (0..DATAGRAMS)
+        .map(|_| {
+            let entities = 8 + next() as usize % 24;
+            let mut datagram = Vec::with_capacity(entities * 16);
+            for entity in 0..entities as u32 {
+                datagram.extend_from_slice(&entity.to_le_bytes());
+                for _ in 0..3 {
+                    let coordinate = 1000 + (next() % 64) as i32;
+                    datagram.extend_from_slice(&coordinate.to_le_bytes());
+                }
+                datagram.push((next() % 4) as u8);
+            }
+
+            datagram
+        })
+        .collect()
+}
+
+fn run(name: &str, compressor: &mut dyn Compressor, datagrams: &[Vec<u8>]) {
+    let mut compressed = vec![0; 8192];
+    let mut decompressed = vec![0; 8192];
+    let mut input_bytes = 0;
+    let mut output_bytes = 0;
+    let mut compress_time = Duration::default();
+    let mut decompress_time = Duration::default();
+
+    for _ in 0..ROUNDS {
+        for datagram in datagrams {
+            let start = Instant::now();
+            let length = compressor.compress(&[datagram], &mut compressed);
+            compress_time += start.elapsed();
+
+            input_bytes += datagram.len();
+            let length = match length {
+                Some(length) => length,
+                None => {
+                    output_bytes += datagram.len();
+                    continue;
+                }
+            };
+            output_bytes += length;
+
+            let start = Instant::now();
+            let restored = compressor.decompress(&compressed[..length], &mut decompressed);
+            decompress_time += start.elapsed();
+            assert_eq!(restored.map(|length| &decompressed[..length]), Some(&datagram[..]));
+        }
+    }
+
+    let megabytes = input_bytes as f64 / (1024.0 * 1024.0);
+    println!(
+        "{:<12} ratio {:>6.3}  compress {:>8.1} MiB/s  decompress {:>8.1} MiB/s",
+        name,
+        output_bytes as f64 / input_bytes as f64,
+        megabytes / compress_time.as_secs_f64(),
+        megabytes / decompress_time.as_secs_f64(),
+    );
+}
+
+fn main() {
+    let datagrams = datagrams();
+    let enet = Enet::new().unwrap();
+
+    run("range coder", &mut RangeCoder::new(&enet).unwrap(), &datagrams);
+
+    #[cfg(feature = "lz4")]
+    run("lz4", &mut enet_rs::compressor::Lz4Compressor::new(), &datagrams);
+
+    #[cfg(feature = "zstd")]
+    {
+        run("zstd", &mut enet_rs::compressor::ZstdCompressor::new(3).unwrap(), &datagrams);
+
+        let samples = &datagrams[..DATAGRAMS / 2];
+        let dictionary = zstd::dict::from_samples(samples, 4096).unwrap();
+        let mut compressor = enet_rs::compressor::ZstdCompressor::with_dictionary(3, &dictionary).unwrap();
+        run("zstd + dict", &mut compressor, &datagrams[DATAGRAMS / 2..]);
+    }
+}
@@ -1,7 +1,61 @@
 use cmake::Config;
-use std::env;
+use std::{env, path::PathBuf};
+
+// the version of vendor/enet, as in src/enet.rs
+const ENET_VERSION_MAJOR: u32 = 1;
+const ENET_VERSION_MINOR: u32 = 3;
+const ENET_VERSION_PATCH: u32 = 17;
 
 fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/layout.c");
+    println!("cargo:rerun-if-changed=vendor/enet");
+    println!("cargo:rerun-if-env-changed=ENET_RS_SYSTEM");
+
+    let include_paths = match system_enet() {
+        Some(include_paths) => include_paths,
+        None => vendored_enet(),
+    };
+
+    // sizes and offsets of the C definitions, for tests/layout.rs
+    cc::Build::new()
+        .file("src/layout.c")
+        .includes(&include_paths)
+        .compile("enet_rs_layout");
+
+    #[cfg(feature = "bindgen")]
+    generate_bindings(&include_paths);
+}
+
+/**
+ * Links the installed libenet dynamically if the `system` feature or
+ * ENET_RS_SYSTEM=1 asks for it and pkg-config finds the vendored version,
+ * returning its include paths.
+ */
+fn system_enet() -> Option<Vec<PathBuf>> {
+    let requested = cfg!(feature = "system") || env::var("ENET_RS_SYSTEM").as_deref() == Ok("1");
+    if !requested {
+        return None;
+    }
+
+    // the structs are shared with C and patch releases change them, e.g. 1.3.18 added a list to ENetPeer
+    let version = format!("{}.{}.{}", ENET_VERSION_MAJOR, ENET_VERSION_MINOR, ENET_VERSION_PATCH);
+
+    match pkg_config::Config::new()
+        .exactly_version(&version)
+        .statik(false)
+        .probe("libenet")
+    {
+        Ok(library) => Some(library.include_paths),
+        Err(e) => {
+            println!("cargo:warning=falling back to the vendored ENet: {}", e);
+            None
+        }
+    }
+}
+
+/** builds vendor/enet and links it statically, returning its include paths */
+fn vendored_enet() -> Vec<PathBuf> {
     let target = env::var("TARGET").unwrap();
     let is_debug = env::var("DEBUG").unwrap() == "true";
 
@@ -22,4 +76,27 @@ fn main() {
     }
 
     println!("cargo:rustc-link-lib=static=enet");
-}
\ No newline at end of file
+
+    vec![PathBuf::from("vendor/enet/include")]
+}
+
+/** generates the `sys` layer, see src/sys/bindings.rs for the pregenerated one */
+#[cfg(feature = "bindgen")]
+fn generate_bindings(include_paths: &[PathBuf]) {
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap()).join("bindings.rs");
+
+    bindgen::Builder::default()
+        .header_contents("enet_rs.h", "#include <enet/enet.h>")
+        .clang_args(include_paths.iter().map(|path| format!("-I{}", path.display())))
+        .allowlist_item("enet_.*|ENet.*|ENET_.*")
+        // platform specific, so taken from header.rs to keep the output the same everywhere
+        .blocklist_item("ENetSocket|ENetSocketSet|ENetBuffer|ENET_SOCKET_NULL")
+        .raw_line("use crate::header::{ENetBuffer, ENetSocket, ENetSocketSet};")
+        .prepend_enum_name(false)
+        .generate_comments(false)
+        .layout_tests(false)
+        .generate()
+        .expect("failed to generate the ENet bindings")
+        .write_to_file(out)
+        .expect("failed to write the ENet bindings");
+}
@@ -0,0 +1,157 @@
+/*
+ * address.rs
+ *
+ * Conversions between ENetAddress and std::net
+ */
+
+use std::{
+    convert::TryFrom,
+    ffi::{CStr, CString},
+    fmt,
+    net::{Ipv4Addr, SocketAddr, SocketAddrV4},
+    str::FromStr,
+};
+
+use libc::{c_char, c_int};
+
+use crate::{
+    enet::{
+        ENET_HOST_ANY,
+        ENetAddress,
+        enet_address_set_host,
+        enet_address_get_host_ip,
+        enet_address_get_host,
+    },
+    error::Error,
+};
+
+/** large enough for any name getnameinfo can produce (NI_MAXHOST) */
+const MAXIMUM_HOST_NAME: usize = 1025;
+
+impl ENetAddress {
+    /**
+     * Creates an address from an IPv4 address and a port.
+     */
+    pub fn new(ip: Ipv4Addr, port: u16) -> ENetAddress {
+        ENetAddress {
+            host: u32::from_ne_bytes(ip.octets()),
+            port,
+        }
+    }
+
+    /**
+     * Creates an address a server host may bind to on every interface.
+     */
+    pub fn any(port: u16) -> ENetAddress {
+        ENetAddress {
+            host: ENET_HOST_ANY,
+            port,
+        }
+    }
+
+    /**
+     * Resolves `host_name`, either a printable IP address or a host name, with
+     * [`enet_address_set_host`].
+     *
+     * [`enet_address_set_host`]: crate::enet::enet_address_set_host
+     */
+    pub fn resolve(host_name: &str, port: u16) -> Result<ENetAddress, Error> {
+        let host_name = CString::new(host_name).map_err(|_| Error::AddressResolution)?;
+        let mut address = ENetAddress::any(port);
+        if unsafe { enet_address_set_host(&mut address, host_name.as_ptr()) } < 0 {
+            return Err(Error::AddressResolution);
+        }
+
+        Ok(address)
+    }
+
+    /** the IPv4 address in host form */
+    pub fn ip(&self) -> Ipv4Addr {
+        Ipv4Addr::from(self.host.to_ne_bytes())
+    }
+
+    pub fn to_socket_addr(&self) -> SocketAddrV4 {
+        SocketAddrV4::new(self.ip(), self.port)
+    }
+
+    /**
+     * Gives the printable form of the IP address with
+     * [`enet_address_get_host_ip`].
+     *
+     * [`enet_address_get_host_ip`]: crate::enet::enet_address_get_host_ip
+     */
+    pub fn host_ip(&self) -> Result<String, Error> {
+        self.get_host_with(enet_address_get_host_ip)
+    }
+
+    /**
+     * Does a reverse lookup of the host with [`enet_address_get_host`],
+     * falling back to the printable IP address.
+     *
+     * [`enet_address_get_host`]: crate::enet::enet_address_get_host
+     */
+    pub fn host_name(&self) -> Result<String, Error> {
+        self.get_host_with(enet_address_get_host)
+    }
+
+    fn get_host_with(
+        &self,
+        get_host: unsafe extern "C" fn(*const ENetAddress, *mut c_char, usize) -> c_int,
+    ) -> Result<String, Error> {
+        let mut buffer = [0 as c_char; MAXIMUM_HOST_NAME];
+        if unsafe { get_host(self, buffer.as_mut_ptr(), buffer.len()) } < 0 {
+            return Err(Error::AddressResolution);
+        }
+
+        let host_name = unsafe { CStr::from_ptr(buffer.as_ptr()) };
+        Ok(host_name.to_string_lossy().into_owned())
+    }
+}
+
+impl From<SocketAddrV4> for ENetAddress {
+    fn from(address: SocketAddrV4) -> ENetAddress {
+        ENetAddress::new(*address.ip(), address.port())
+    }
+}
+
+impl TryFrom<SocketAddr> for ENetAddress {
+    type Error = Error;
+
+    fn try_from(address: SocketAddr) -> Result<ENetAddress, Error> {
+        match address {
+            SocketAddr::V4(address) => Ok(address.into()),
+            SocketAddr::V6(_) => Err(Error::UnsupportedAddress),
+        }
+    }
+}
+
+impl From<ENetAddress> for SocketAddrV4 {
+    fn from(address: ENetAddress) -> SocketAddrV4 {
+        address.to_socket_addr()
+    }
+}
+
+impl From<ENetAddress> for SocketAddr {
+    fn from(address: ENetAddress) -> SocketAddr {
+        SocketAddr::V4(address.to_socket_addr())
+    }
+}
+
+impl fmt::Display for ENetAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_socket_addr(), f)
+    }
+}
+
+/**
+ * Parses an `ip:port` pair. Use [`ENetAddress::resolve`] for host names.
+ */
+impl FromStr for ENetAddress {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<ENetAddress, Error> {
+        s.parse::<SocketAddrV4>()
+            .map(ENetAddress::from)
+            .map_err(|_| Error::AddressResolution)
+    }
+}
@@ -0,0 +1,178 @@
+/*
+ * allocator.rs
+ *
+ * Rust allocators for ENet's memory, installed through ENetCallbacks
+ */
+
+use std::{
+    alloc::{self, Layout},
+    cell::Cell,
+    panic::{self, AssertUnwindSafe},
+    process,
+    ptr,
+    sync::atomic::{AtomicPtr, Ordering},
+};
+
+use libc::c_void;
+
+use crate::{callbacks::ENetCallbacks, error::Error};
+
+/**
+ * An allocator for all memory ENet allocates, installed with
+ * [`Enet::with_allocator`].
+ *
+ * `ENetCallbacks` carries no context and ENet keeps using its callbacks for
+ * the rest of the process, so an allocator is installed at most once and
+ * never dropped.
+ *
+ * [`Enet::with_allocator`]: crate::context::Enet::with_allocator
+ */
+pub trait EnetAllocator: Send + Sync + 'static {
+    /**
+     * Allocates `size` bytes aligned for any C type, returning null on
+     * failure.
+     */
+    fn alloc(&self, size: usize) -> *mut c_void;
+
+    /**
+     * Frees memory returned by [`alloc`](EnetAllocator::alloc).
+     *
+     * # Safety
+     * `memory` is null or was returned by `alloc` of this allocator and not
+     * freed since.
+     */
+    unsafe fn free(&self, memory: *mut c_void);
+
+    /**
+     * Called by ENet when [`alloc`](EnetAllocator::alloc) returned null.
+     *
+     * ENet's default aborts the process. Returning instead lets the failed
+     * allocation surface as an error from the ENet function that needed it,
+     * e.g. [`Error::HostCreateFailed`] without an OS error, or
+     * [`Error::PacketCreateFailed`].
+     *
+     * [`Error::HostCreateFailed`]: crate::error::Error::HostCreateFailed
+     * [`Error::PacketCreateFailed`]: crate::error::Error::PacketCreateFailed
+     */
+    fn no_memory(&self) {}
+}
+
+/**
+ * Routes ENet's memory to Rust's global allocator, so it is accounted for by
+ * whatever `#[global_allocator]` the program uses.
+ */
+#[derive(Debug, Default, Copy, Clone)]
+pub struct RustAllocator;
+
+/** room before each allocation for its size, keeping the alignment of `malloc` */
+const HEADER: usize = 16;
+
+impl EnetAllocator for RustAllocator {
+    fn alloc(&self, size: usize) -> *mut c_void {
+        let layout = match size.checked_add(HEADER).and_then(|total| Layout::from_size_align(total, HEADER).ok()) {
+            Some(layout) => layout,
+            None => return ptr::null_mut(),
+        };
+
+        unsafe {
+            let memory = alloc::alloc(layout);
+            if memory.is_null() {
+                return ptr::null_mut();
+            }
+
+            (memory as *mut usize).write(layout.size());
+            memory.add(HEADER) as *mut c_void
+        }
+    }
+
+    unsafe fn free(&self, memory: *mut c_void) {
+        if memory.is_null() {
+            return;
+        }
+
+        let memory = (memory as *mut u8).sub(HEADER);
+        let size = (memory as *mut usize).read();
+        alloc::dealloc(memory, Layout::from_size_align_unchecked(size, HEADER));
+    }
+}
+
+/** the installed allocator, leaked for the rest of the process */
+static ALLOCATOR: AtomicPtr<Box<dyn EnetAllocator>> = AtomicPtr::new(ptr::null_mut());
+
+/**
+ * Installs `allocator` for the trampolines, failing if an allocator was
+ * installed before.
+ */
+pub(crate) fn install<A: EnetAllocator>(allocator: A) -> Result<(), Error> {
+    let allocator: *mut Box<dyn EnetAllocator> = Box::into_raw(Box::new(Box::new(allocator)));
+    if ALLOCATOR.compare_exchange(ptr::null_mut(), allocator, Ordering::AcqRel, Ordering::Acquire).is_err() {
+        unsafe { drop(Box::from_raw(allocator)) }
+        return Err(Error::AllocatorUnavailable);
+    }
+
+    Ok(())
+}
+
+/**
+ * Callbacks forwarding to the installed allocator, or to `malloc` and `free`
+ * when there is none.
+ */
+pub(crate) fn callbacks() -> ENetCallbacks {
+    ENetCallbacks { malloc: Some(malloc), free: Some(free), no_memory: Some(no_memory) }
+}
+
+thread_local! {
+    /** whether an allocation failed on this thread since the last check */
+    static OUT_OF_MEMORY: Cell<bool> = const { Cell::new(false) };
+}
+
+/**
+ * Whether an allocation by ENet failed on this thread since the last call,
+ * to tell a failure for want of memory apart from one of the OS.
+ */
+pub(crate) fn take_out_of_memory() -> bool {
+    OUT_OF_MEMORY.with(|out_of_memory| out_of_memory.replace(false))
+}
+
+fn allocator() -> Option<&'static dyn EnetAllocator> {
+    unsafe { ALLOCATOR.load(Ordering::Acquire).as_ref().map(|allocator| &**allocator) }
+}
+
+unsafe extern "C" fn malloc(size: usize) -> *mut c_void {
+    let memory = match allocator() {
+        // a panic must not unwind into ENet, treat it as a failed allocation
+        Some(allocator) => panic::catch_unwind(AssertUnwindSafe(|| allocator.alloc(size))).unwrap_or(ptr::null_mut()),
+        None => libc::malloc(size),
+    };
+
+    #[cfg(feature = "memory-stats")]
+    crate::memory_stats::record_alloc(memory, size);
+
+    memory
+}
+
+unsafe extern "C" fn free(memory: *mut c_void) {
+    #[cfg(feature = "memory-stats")]
+    crate::memory_stats::record_free(memory);
+
+    match allocator() {
+        Some(allocator) => {
+            if panic::catch_unwind(AssertUnwindSafe(|| allocator.free(memory))).is_err() {
+                // ENet cannot tell a failed free apart, carrying on would leak or corrupt
+                process::abort();
+            }
+        }
+        None => libc::free(memory),
+    }
+}
+
+unsafe extern "C" fn no_memory() {
+    OUT_OF_MEMORY.with(|out_of_memory| out_of_memory.set(true));
+    match allocator() {
+        Some(allocator) => {
+            let _ = panic::catch_unwind(AssertUnwindSafe(|| allocator.no_memory()));
+        }
+        // what ENet does by default
+        None => process::abort(),
+    }
+}
@@ -0,0 +1,192 @@
+/*
+ * async_host.rs
+ *
+ * ENet host driven by the tokio reactor
+ */
+
+use std::{
+    future::{self, Future},
+    io,
+    os::unix::io::RawFd,
+    pin::Pin,
+    ptr,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use futures_core::Stream;
+use tokio::{
+    io::unix::AsyncFd,
+    time::{self, Instant, Sleep},
+};
+
+use crate::{
+    enet::ENetEvent,
+    error::Error,
+    event::{Event, OwnedEvent},
+    host::Host,
+};
+
+/**
+ * A [`Host`] serviced by the tokio reactor instead of a blocking
+ * `enet_host_service` loop.
+ *
+ * The host's socket is registered with the reactor, so received datagrams
+ * wake the task right away. ENet has no timer of its own to wait on: like
+ * `enet_host_service`, it resends, pings and times out peers whenever it is
+ * serviced, so the task also sleeps until [`Host::next_timeout`] says the
+ * host is due, and not at all while no peer needs it. Packets queued on the
+ * host are flushed before waiting; call [`Host::flush`] to send them right
+ * away from outside the event loop.
+ *
+ * Events come from [`AsyncHost::next_event`], or as [`OwnedEvent`]s from the
+ * host used as a [`Stream`], which never ends.
+ *
+ * Only available on Unix, where tokio can watch a raw socket.
+ *
+ * [`Host`]: crate::host::Host
+ * [`Host::next_timeout`]: crate::host::Host::next_timeout
+ * [`Host::flush`]: crate::host::Host::flush
+ * [`OwnedEvent`]: crate::event::OwnedEvent
+ */
+#[derive(Debug)]
+pub struct AsyncHost<T = ()> {
+    // deregistered before the host closes the socket
+    socket: AsyncFd<RawFd>,
+    host: Host<T>,
+    timer: Pin<Box<Sleep>>,
+}
+
+// no field is ever pinned through the host
+impl<T> Unpin for AsyncHost<T> {}
+
+impl<T> AsyncHost<T> {
+    /**
+     * Registers the socket of `host` with the reactor of the current tokio
+     * runtime.
+     *
+     * # Panics
+     * When called outside of a tokio runtime.
+     */
+    pub fn new(host: Host<T>) -> Result<AsyncHost<T>, Error> {
+        let socket = unsafe { (*host.as_raw()).socket };
+        Ok(AsyncHost {
+            socket: AsyncFd::new(socket)?,
+            host,
+            timer: Box::pin(time::sleep(Duration::from_millis(0))),
+        })
+    }
+
+    pub fn get_ref(&self) -> &Host<T> {
+        &self.host
+    }
+
+    /** the host, e.g. to send packets to its peers */
+    pub fn get_mut(&mut self) -> &mut Host<T> {
+        &mut self.host
+    }
+
+    /** deregisters the socket, giving the host back */
+    pub fn into_inner(self) -> Host<T> {
+        let AsyncHost { socket, host, .. } = self;
+        drop(socket);
+        host
+    }
+
+    /**
+     * Waits for the next event on the host.
+     */
+    pub async fn next_event(&mut self) -> Result<Event<'_, T>, Error> {
+        loop {
+            let event = future::poll_fn(|cx| self.poll_raw_event(cx)).await?;
+            if let Some(event) = unsafe { Event::from_raw(&event) } {
+                return Ok(event);
+            }
+        }
+    }
+
+    fn poll_raw_event(&mut self, cx: &mut Context<'_>) -> Poll<Result<ENetEvent, Error>> {
+        loop {
+            if let Some(event) = self.host.check_events_raw()? {
+                return Poll::Ready(Ok(event));
+            }
+
+            let readable = match self.poll_readable(cx) {
+                Poll::Ready(Ok(())) => true,
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(Error::Socket(e))),
+                Poll::Pending => false,
+            };
+
+            if !readable {
+                // the caller may have queued packets since the host was last serviced
+                self.host.flush();
+
+                let due = match self.host.next_timeout() {
+                    Some(timeout) => {
+                        self.timer.as_mut().reset(Instant::now() + timeout);
+                        self.timer.as_mut().poll(cx).is_ready()
+                    }
+                    None => false,
+                };
+                if !due {
+                    return Poll::Pending;
+                }
+            }
+
+            // receives up to 256 datagrams, then resends, pings and times out peers
+            if let Some(event) = self.host.service_raw(0)? {
+                return Poll::Ready(Ok(event));
+            }
+        }
+    }
+
+    /**
+     * Whether a datagram is waiting on the socket. Readiness is only cleared
+     * once the socket would block, since servicing the host once may leave
+     * datagrams behind.
+     */
+    fn poll_readable(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        loop {
+            let mut guard = match self.socket.poll_read_ready(cx) {
+                Poll::Ready(guard) => guard?,
+                Poll::Pending => return Poll::Pending,
+            };
+
+            if let Ok(result) = guard.try_io(|socket| peek_datagram(*socket.get_ref())) {
+                return Poll::Ready(result);
+            }
+        }
+    }
+}
+
+/** checks for a datagram without receiving it, failing with `WouldBlock` if there is none */
+fn peek_datagram(socket: RawFd) -> io::Result<()> {
+    let result = unsafe {
+        libc::recv(socket, ptr::null_mut(), 0, libc::MSG_PEEK | libc::MSG_DONTWAIT)
+    };
+    if result < 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    Ok(())
+}
+
+impl<T> Stream for AsyncHost<T> {
+    type Item = Result<OwnedEvent, Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            let event = match this.poll_raw_event(cx) {
+                Poll::Ready(Ok(event)) => event,
+                Poll::Ready(Err(e)) => return Poll::Ready(Some(Err(e))),
+                Poll::Pending => return Poll::Pending,
+            };
+
+            if let Some(event) = unsafe { Event::<T>::from_raw(&event) } {
+                return Poll::Ready(Some(Ok(event.into())));
+            }
+        }
+    }
+}
+
@@ -0,0 +1,323 @@
+/*
+ * builder.rs
+ *
+ * Builder for ENet hosts
+ */
+
+use std::{fmt, mem};
+
+use crate::{
+    checksum::{Checksum, Crc32},
+    compressor::{self, Compressor},
+    context::Enet,
+    enet::{
+        ENetAddress,
+        ENetChecksumCallback,
+        ENetCompressor,
+        ENetInterceptCallback,
+        ENET_HOST_DEFAULT_MTU,
+        ENET_HOST_DEFAULT_MAXIMUM_PACKET_SIZE,
+        ENET_HOST_DEFAULT_MAXIMUM_WAITING_DATA,
+        enet_host_compress,
+        enet_host_compress_with_range_coder,
+    },
+    error::Error,
+    host::Host,
+    intercept::{Callback, InterceptAction, InterceptContext},
+    protocol::{
+        ENET_PROTOCOL_MINIMUM_MTU,
+        ENET_PROTOCOL_MAXIMUM_MTU,
+        ENET_PROTOCOL_MINIMUM_CHANNEL_COUNT,
+        ENET_PROTOCOL_MAXIMUM_CHANNEL_COUNT,
+        ENET_PROTOCOL_MAXIMUM_PEER_ID,
+    },
+};
+
+/** compression to install on the host once it is created */
+#[derive(Debug)]
+enum Compression {
+    None,
+    RangeCoder,
+    Custom(ENetCompressor),
+}
+
+/** intercept to install on the host once it is created */
+enum Interception {
+    None,
+    Raw(ENetInterceptCallback),
+    Hook(Box<Callback>),
+}
+
+impl fmt::Debug for Interception {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Interception::None => f.write_str("None"),
+            Interception::Raw(callback) => f.debug_tuple("Raw").field(callback).finish(),
+            Interception::Hook(_) => f.write_str("Hook"),
+        }
+    }
+}
+
+/**
+ * Builds a [`Host`], exposing the tunables of `ENetHost` that can otherwise
+ * only be set by writing its fields after creation.
+ *
+ * Every value is validated against the protocol limits before the host is
+ * created.
+ *
+ * [`Host`]: crate::host::Host
+ */
+#[derive(Debug)]
+pub struct HostBuilder {
+    address: Option<ENetAddress>,
+    peer_limit: usize,
+    channel_limit: usize,
+    incoming_bandwidth: u32,
+    outgoing_bandwidth: u32,
+    mtu: u32,
+    duplicate_peers: usize,
+    max_packet_size: usize,
+    max_waiting_data: usize,
+    checksum: ENetChecksumCallback,
+    compression: Compression,
+    intercept: Interception,
+}
+
+impl Default for HostBuilder {
+    fn default() -> HostBuilder {
+        HostBuilder::new()
+    }
+}
+
+impl HostBuilder {
+    /**
+     * Creates a builder for a host with a single peer and the maximum number
+     * of channels, that does not accept incoming connections.
+     */
+    pub fn new() -> HostBuilder {
+        HostBuilder {
+            address: None,
+            peer_limit: 1,
+            channel_limit: ENET_PROTOCOL_MAXIMUM_CHANNEL_COUNT as usize,
+            incoming_bandwidth: 0,
+            outgoing_bandwidth: 0,
+            mtu: ENET_HOST_DEFAULT_MTU as u32,
+            duplicate_peers: ENET_PROTOCOL_MAXIMUM_PEER_ID as usize,
+            max_packet_size: ENET_HOST_DEFAULT_MAXIMUM_PACKET_SIZE as usize,
+            max_waiting_data: ENET_HOST_DEFAULT_MAXIMUM_WAITING_DATA as usize,
+            checksum: None,
+            compression: Compression::None,
+            intercept: Interception::None,
+        }
+    }
+
+    /** the address at which other peers may connect to this host */
+    pub fn bind(mut self, address: ENetAddress) -> HostBuilder {
+        self.address = Some(address);
+        self
+    }
+
+    /** the maximum number of peers that should be allocated for the host */
+    pub fn peer_limit(mut self, peer_limit: usize) -> HostBuilder {
+        self.peer_limit = peer_limit;
+        self
+    }
+
+    /** the maximum number of channels allowed for connected peers */
+    pub fn channel_limit(mut self, channel_limit: usize) -> HostBuilder {
+        self.channel_limit = channel_limit;
+        self
+    }
+
+    /**
+     * Downstream and upstream bandwidth of the host in bytes/second; 0 means
+     * unlimited.
+     */
+    pub fn bandwidth(mut self, incoming_bandwidth: u32, outgoing_bandwidth: u32) -> HostBuilder {
+        self.incoming_bandwidth = incoming_bandwidth;
+        self.outgoing_bandwidth = outgoing_bandwidth;
+        self
+    }
+
+    /** the MTU offered to peers, defaults to ENET_HOST_DEFAULT_MTU */
+    pub fn mtu(mut self, mtu: u32) -> HostBuilder {
+        self.mtu = mtu;
+        self
+    }
+
+    /** number of allowed peers from the same IP, defaults to ENET_PROTOCOL_MAXIMUM_PEER_ID */
+    pub fn duplicate_peers(mut self, duplicate_peers: usize) -> HostBuilder {
+        self.duplicate_peers = duplicate_peers;
+        self
+    }
+
+    /** the maximum allowable packet size that may be sent or received on a peer */
+    pub fn max_packet_size(mut self, max_packet_size: usize) -> HostBuilder {
+        self.max_packet_size = max_packet_size;
+        self
+    }
+
+    /** the maximum aggregate amount of buffer space a peer may use waiting for packets to be delivered */
+    pub fn max_waiting_data(mut self, max_waiting_data: usize) -> HostBuilder {
+        self.max_waiting_data = max_waiting_data;
+        self
+    }
+
+    /**
+     * Callback computing packet checksums, e.g. `enet_crc32`.
+     *
+     * # Safety
+     * The callback must be sound to call with any buffers ENet hands it, for
+     * as long as the host lives.
+     */
+    pub unsafe fn checksum(mut self, checksum: ENetChecksumCallback) -> HostBuilder {
+        self.checksum = checksum;
+        self
+    }
+
+    /** checksums packets with `C` */
+    pub fn checksum_with<C: Checksum>(self) -> HostBuilder {
+        unsafe { self.checksum(C::callback()) }
+    }
+
+    /** checksums packets with ENet's CRC32 */
+    pub fn crc32(self) -> HostBuilder {
+        self.checksum_with::<Crc32>()
+    }
+
+    /** compresses packets with ENet's built-in range coder */
+    pub fn range_coder(mut self) -> HostBuilder {
+        self.compression = Compression::RangeCoder;
+        self
+    }
+
+    /** compresses packets with a Rust compressor */
+    pub fn compressor<C: Compressor + Send + 'static>(mut self, compressor: C) -> HostBuilder {
+        self.destroy_compressor();
+        self.compression = Compression::Custom(compressor::into_raw(compressor));
+        self
+    }
+
+    /**
+     * Compresses packets with a raw compressor.
+     *
+     * # Safety
+     * The compressor's callbacks must be sound to call with its context for
+     * as long as the host lives, and `destroy` must free the context.
+     */
+    pub unsafe fn raw_compressor(mut self, compressor: ENetCompressor) -> HostBuilder {
+        self.destroy_compressor();
+        self.compression = Compression::Custom(compressor);
+        self
+    }
+
+    /** frees a custom compressor the host has not taken over */
+    fn destroy_compressor(&mut self) {
+        if let Compression::Custom(ENetCompressor { context, destroy: Some(destroy), .. }) = self.compression {
+            unsafe { destroy(context) }
+        }
+
+        self.compression = Compression::None;
+    }
+
+    /** hook intercepting received raw UDP packets, see `Host::set_intercept` */
+    pub fn intercept<F>(mut self, hook: F) -> HostBuilder
+    where
+        F: FnMut(InterceptContext<'_>) -> InterceptAction + Send + 'static,
+    {
+        self.intercept = Interception::Hook(Box::new(hook));
+        self
+    }
+
+    /**
+     * Raw callback intercepting received raw UDP packets.
+     *
+     * # Safety
+     * The callback must be sound to call with the host and any event ENet
+     * hands it, for as long as the host lives.
+     */
+    pub unsafe fn raw_intercept(mut self, intercept: ENetInterceptCallback) -> HostBuilder {
+        self.intercept = Interception::Raw(intercept);
+        self
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        let check = |option, value: usize, minimum: i32, maximum: i32| {
+            if value < minimum as usize || value > maximum as usize {
+                return Err(Error::InvalidConfig { option, value });
+            }
+
+            Ok(())
+        };
+
+        check("peer_limit", self.peer_limit, 1, ENET_PROTOCOL_MAXIMUM_PEER_ID)?;
+        check(
+            "channel_limit",
+            self.channel_limit,
+            ENET_PROTOCOL_MINIMUM_CHANNEL_COUNT,
+            ENET_PROTOCOL_MAXIMUM_CHANNEL_COUNT,
+        )?;
+        check("mtu", self.mtu as usize, ENET_PROTOCOL_MINIMUM_MTU, ENET_PROTOCOL_MAXIMUM_MTU)?;
+        check("duplicate_peers", self.duplicate_peers, 1, ENET_PROTOCOL_MAXIMUM_PEER_ID)?;
+        if self.max_packet_size == 0 {
+            return Err(Error::InvalidConfig { option: "max_packet_size", value: 0 });
+        }
+
+        Ok(())
+    }
+
+    /**
+     * Validates the configuration and creates the host.
+     */
+    pub fn build<T>(mut self, enet: &Enet) -> Result<Host<T>, Error> {
+        let mut host = match self.validate().and_then(|_| {
+            Host::new(
+                enet,
+                self.address.as_ref(),
+                self.peer_limit,
+                self.channel_limit,
+                self.incoming_bandwidth,
+                self.outgoing_bandwidth,
+            )
+        }) {
+            Ok(host) => host,
+            Err(e) => {
+                self.destroy_compressor();
+                return Err(e);
+            }
+        };
+
+        unsafe {
+            let raw = &mut *host.as_raw();
+            raw.mtu = self.mtu;
+            raw.duplicatePeers = self.duplicate_peers;
+            raw.maximumPacketSize = self.max_packet_size;
+            raw.maximumWaitingData = self.max_waiting_data;
+            raw.checksum = self.checksum;
+
+            match mem::replace(&mut self.compression, Compression::None) {
+                Compression::None => {}
+                Compression::RangeCoder => {
+                    if enet_host_compress_with_range_coder(host.as_raw()) < 0 {
+                        return Err(Error::CompressorFailed);
+                    }
+                }
+                Compression::Custom(compressor) => enet_host_compress(host.as_raw(), &compressor),
+            }
+        }
+
+        match mem::replace(&mut self.intercept, Interception::None) {
+            Interception::None => {}
+            Interception::Raw(intercept) => unsafe { (*host.as_raw()).intercept = intercept },
+            Interception::Hook(hook) => host.set_intercept(hook),
+        }
+
+        Ok(host)
+    }
+}
+
+impl Drop for HostBuilder {
+    fn drop(&mut self) {
+        self.destroy_compressor();
+    }
+}
@@ -0,0 +1,84 @@
+/*
+ * checksum.rs
+ *
+ * Packet checksums for ENetHost.checksum
+ */
+
+use std::{
+    panic,
+    slice,
+};
+
+use libc::{c_void, size_t};
+
+use crate::{
+    enet::{ENetChecksumCallback, enet_crc32},
+    header::ENetBuffer,
+    types::enet_uint32,
+};
+
+/**
+ * A checksum ENet computes over every datagram and verifies on receipt,
+ * installed with [`Host::set_checksum`] or [`HostBuilder::checksum_with`].
+ *
+ * `ENetChecksumCallback` carries no context, so a checksum is a type rather
+ * than a value. Both ends of a connection must use the same checksum.
+ *
+ * [`Host::set_checksum`]: crate::host::Host::set_checksum
+ * [`HostBuilder::checksum_with`]: crate::builder::HostBuilder::checksum_with
+ */
+pub trait Checksum {
+    /** computes the checksum of the concatenation of `buffers` */
+    fn checksum(buffers: &[&[u8]]) -> u32;
+
+    /** the raw callback installed in `ENetHost.checksum` */
+    fn callback() -> ENetChecksumCallback
+    where
+        Self: Sized,
+    {
+        Some(checksum::<Self>)
+    }
+}
+
+/**
+ * ENet's own CRC32, computed by [`enet_crc32`].
+ *
+ * [`enet_crc32`]: crate::enet::enet_crc32
+ */
+#[derive(Debug, Copy, Clone)]
+pub struct Crc32;
+
+impl Checksum for Crc32 {
+    fn checksum(buffers: &[&[u8]]) -> u32 {
+        crc32(buffers)
+    }
+
+    fn callback() -> ENetChecksumCallback {
+        Some(enet_crc32)
+    }
+}
+
+/**
+ * Computes ENet's CRC32 of the concatenation of `buffers` with
+ * [`enet_crc32`].
+ *
+ * [`enet_crc32`]: crate::enet::enet_crc32
+ */
+pub fn crc32(buffers: &[&[u8]]) -> u32 {
+    let buffers = buffers
+        .iter()
+        .map(|buffer| ENetBuffer { dataLength: buffer.len(), data: buffer.as_ptr() as *mut c_void })
+        .collect::<Vec<_>>();
+
+    unsafe { enet_crc32(buffers.as_ptr(), buffers.len()) }
+}
+
+unsafe extern "C" fn checksum<C: Checksum>(buffers: *const ENetBuffer, bufferCount: size_t) -> enet_uint32 {
+    let buffers = slice::from_raw_parts(buffers, bufferCount)
+        .iter()
+        .map(|buffer| buffer.as_slice())
+        .collect::<Vec<_>>();
+
+    // a panic must not unwind into ENet, a wrong checksum drops the datagram instead
+    panic::catch_unwind(|| C::checksum(&buffers)).unwrap_or(0)
+}
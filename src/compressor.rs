@@ -0,0 +1,206 @@
+/*
+ * compressor.rs
+ *
+ * Rust packet compressors bridged to ENetCompressor
+ */
+
+use std::{
+    panic::{self, AssertUnwindSafe},
+    slice,
+};
+
+use libc::{c_void, size_t};
+
+use crate::{
+    enet::ENetCompressor,
+    header::ENetBuffer,
+    types::enet_uint8,
+};
+
+/**
+ * A packet compressor run by ENet on every outgoing and incoming UDP
+ * datagram, installed with [`Host::set_compressor`] or
+ * [`HostBuilder::compressor`].
+ *
+ * Both ends of a connection must use the same compressor; ENet does not
+ * negotiate it.
+ *
+ * [`Host::set_compressor`]: crate::host::Host::set_compressor
+ * [`HostBuilder::compressor`]: crate::builder::HostBuilder::compressor
+ */
+pub trait Compressor {
+    /**
+     * Compresses the concatenation of `input` into `out`, returning the
+     * number of bytes written, or None if the data could not be compressed
+     * into `out`, in which case ENet sends it uncompressed.
+     */
+    fn compress(&mut self, input: &[&[u8]], out: &mut [u8]) -> Option<usize>;
+
+    /**
+     * Decompresses `input` into `out`, returning the number of bytes written,
+     * or None if the data is corrupt or does not fit, in which case ENet
+     * drops the datagram.
+     */
+    fn decompress(&mut self, input: &[u8], out: &mut [u8]) -> Option<usize>;
+}
+
+/**
+ * Boxes `compressor` into the context of a raw `ENetCompressor` whose
+ * callbacks forward to it. The context is freed by the `destroy` callback,
+ * which ENet calls when compression is replaced or the host is destroyed.
+ */
+pub(crate) fn into_raw<C: Compressor + Send + 'static>(compressor: C) -> ENetCompressor {
+    ENetCompressor {
+        context: Box::into_raw(Box::new(compressor)) as *mut c_void,
+        compress: Some(compress::<C>),
+        decompress: Some(decompress::<C>),
+        destroy: Some(destroy::<C>),
+    }
+}
+
+unsafe extern "C" fn compress<C: Compressor>(
+    context: *mut c_void,
+    inBuffers: *const ENetBuffer,
+    inBufferCount: size_t,
+    _inLimit: size_t,
+    outData: *mut enet_uint8,
+    outLimit: size_t,
+) -> size_t {
+    let compressor = &mut *(context as *mut C);
+    let input = slice::from_raw_parts(inBuffers, inBufferCount)
+        .iter()
+        .map(|buffer| buffer.as_slice())
+        .collect::<Vec<_>>();
+    let out = slice::from_raw_parts_mut(outData, outLimit);
+
+    // a panic must not unwind into ENet, treat it as a failure to compress
+    panic::catch_unwind(AssertUnwindSafe(|| compressor.compress(&input, out)))
+        .ok()
+        .flatten()
+        .filter(|&length| length <= outLimit)
+        .unwrap_or(0)
+}
+
+unsafe extern "C" fn decompress<C: Compressor>(
+    context: *mut c_void,
+    inData: *const enet_uint8,
+    inLimit: size_t,
+    outData: *mut enet_uint8,
+    outLimit: size_t,
+) -> size_t {
+    let compressor = &mut *(context as *mut C);
+    let input = slice::from_raw_parts(inData, inLimit);
+    let out = slice::from_raw_parts_mut(outData, outLimit);
+
+    panic::catch_unwind(AssertUnwindSafe(|| compressor.decompress(input, out)))
+        .ok()
+        .flatten()
+        .filter(|&length| length <= outLimit)
+        .unwrap_or(0)
+}
+
+unsafe extern "C" fn destroy<C>(context: *mut c_void) {
+    drop(Box::from_raw(context as *mut C));
+}
+
+/**
+ * Concatenates the buffers of an outgoing datagram into `scratch`, or borrows
+ * the only buffer when there is just one.
+ */
+#[cfg(any(feature = "lz4", feature = "zstd"))]
+fn concat<'a>(input: &[&'a [u8]], scratch: &'a mut Vec<u8>) -> &'a [u8] {
+    if let [buffer] = input {
+        return buffer;
+    }
+
+    scratch.clear();
+    for buffer in input {
+        scratch.extend_from_slice(buffer);
+    }
+
+    scratch
+}
+
+/**
+ * Compresses packets with LZ4 block compression, favouring speed over ratio.
+ *
+ * There is no negotiation or framing: both ends must be configured with an
+ * `Lz4Compressor`.
+ */
+#[cfg(feature = "lz4")]
+#[derive(Debug, Default)]
+pub struct Lz4Compressor {
+    scratch: Vec<u8>,
+}
+
+#[cfg(feature = "lz4")]
+impl Lz4Compressor {
+    pub fn new() -> Lz4Compressor {
+        Lz4Compressor::default()
+    }
+}
+
+#[cfg(feature = "lz4")]
+impl Compressor for Lz4Compressor {
+    fn compress(&mut self, input: &[&[u8]], out: &mut [u8]) -> Option<usize> {
+        let input = concat(input, &mut self.scratch);
+        lz4_flex::block::compress_into(input, out).ok()
+    }
+
+    fn decompress(&mut self, input: &[u8], out: &mut [u8]) -> Option<usize> {
+        lz4_flex::block::decompress_into(input, out).ok()
+    }
+}
+
+/**
+ * Compresses packets with zstd, optionally with a dictionary trained on
+ * representative traffic, which matters for datagrams this small.
+ *
+ * There is no negotiation or framing: both ends must be configured with a
+ * `ZstdCompressor` using the same dictionary.
+ */
+#[cfg(feature = "zstd")]
+pub struct ZstdCompressor {
+    compressor: zstd::bulk::Compressor<'static>,
+    decompressor: zstd::bulk::Decompressor<'static>,
+    scratch: Vec<u8>,
+}
+
+#[cfg(feature = "zstd")]
+impl ZstdCompressor {
+    /**
+     * Creates a compressor without a dictionary.
+     * ### Parameters
+     * ⠀⠀⠀⠀⠀*level: zstd compression level, 0 selects the default*
+     */
+    pub fn new(level: i32) -> std::io::Result<ZstdCompressor> {
+        ZstdCompressor::with_dictionary(level, &[])
+    }
+
+    /**
+     * Creates a compressor using a dictionary, e.g. one produced by
+     * `zstd::dict::from_samples` over captured packets.
+     * ### Parameters
+     * ⠀⠀⠀⠀⠀*level: zstd compression level, 0 selects the default \
+     * ⠀⠀⠀⠀⠀dictionary: dictionary shared by both ends*
+     */
+    pub fn with_dictionary(level: i32, dictionary: &[u8]) -> std::io::Result<ZstdCompressor> {
+        Ok(ZstdCompressor {
+            compressor: zstd::bulk::Compressor::with_dictionary(level, dictionary)?,
+            decompressor: zstd::bulk::Decompressor::with_dictionary(dictionary)?,
+            scratch: Vec::new(),
+        })
+    }
+}
+
+#[cfg(feature = "zstd")]
+impl Compressor for ZstdCompressor {
+    fn compress(&mut self, input: &[&[u8]], out: &mut [u8]) -> Option<usize> {
+        let input = concat(input, &mut self.scratch);
+        self.compressor.compress_to_buffer(input, out).ok()
+    }
+
+    fn decompress(&mut self, input: &[u8], out: &mut [u8]) -> Option<usize> {
+        self.decompressor.decompress_to_buffer(input, out).ok()
+    }
+}
@@ -0,0 +1,156 @@
+/*
+ * context.rs
+ *
+ * Reference counted ENet global initialization
+ */
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Mutex,
+};
+
+use libc::c_int;
+
+#[cfg(feature = "memory-stats")]
+use crate::memory_stats::{self, MemoryStats};
+use crate::{
+    allocator::{self, EnetAllocator},
+    enet::{enet_initialize_with_callbacks, enet_deinitialize, enet_linked_version},
+    error::Error,
+    ENET_VERSION,
+    ENET_VERSION_GET_MAJOR,
+    ENET_VERSION_GET_MINOR,
+    ENET_VERSION_GET_PATCH,
+};
+
+/** number of live `Enet` handles in the process */
+static HANDLES: Mutex<usize> = Mutex::new(0);
+
+/** whether ENet has been initialized in the process, with or without an allocator */
+static INITIALIZED: AtomicBool = AtomicBool::new(false);
+
+/**
+ * A handle keeping ENet globally initialized.
+ *
+ * The first handle created calls [`enet_initialize`] and the last one dropped
+ * calls [`enet_deinitialize`], so independent users of ENet in the same
+ * process cannot shut it down under each other. Cloning a handle is cheap.
+ *
+ * [`enet_initialize`]: crate::enet::enet_initialize
+ * [`enet_deinitialize`]: crate::enet::enet_deinitialize
+ */
+#[derive(Debug)]
+pub struct Enet {
+    _private: (),
+}
+
+impl Enet {
+    /**
+     * Initializes ENet globally if no other handle is alive.
+     *
+     * Fails if the linked library is not exactly the version these bindings
+     * were written for, since even patch releases change the structs shared
+     * with it, or if [`enet_initialize`] fails.
+     *
+     * [`enet_initialize`]: crate::enet::enet_initialize
+     */
+    pub fn new() -> Result<Enet, Error> {
+        let mut handles = HANDLES.lock().unwrap_or_else(|e| e.into_inner());
+        if *handles == 0 {
+            check_linked_version()?;
+            if unsafe { initialize() } < 0 {
+                return Err(Error::InitFailed);
+            }
+        }
+
+        INITIALIZED.store(true, Ordering::Release);
+        *handles += 1;
+        Ok(Enet { _private: () })
+    }
+
+    /**
+     * Initializes ENet with `allocator` serving all of its memory, e.g. to
+     * account for it in a memory budget.
+     *
+     * ENet keeps its callbacks for the rest of the process, so this must be
+     * the first initialization of ENet in the process, and later handles
+     * created with [`Enet::new`] keep using `allocator`. Fails with
+     * [`Error::AllocatorUnavailable`] otherwise.
+     *
+     * [`Error::AllocatorUnavailable`]: crate::error::Error::AllocatorUnavailable
+     */
+    pub fn with_allocator<A: EnetAllocator>(allocator: A) -> Result<Enet, Error> {
+        let mut handles = HANDLES.lock().unwrap_or_else(|e| e.into_inner());
+        if INITIALIZED.load(Ordering::Acquire) {
+            return Err(Error::AllocatorUnavailable);
+        }
+
+        check_linked_version()?;
+        allocator::install(allocator)?;
+        if unsafe { enet_initialize_with_callbacks(ENET_VERSION!(), &allocator::callbacks()) } < 0 {
+            return Err(Error::InitFailed);
+        }
+
+        INITIALIZED.store(true, Ordering::Release);
+        *handles += 1;
+        Ok(Enet { _private: () })
+    }
+
+    /**
+     * Gives the ENet allocations currently live, by category.
+     */
+    #[cfg(feature = "memory-stats")]
+    pub fn memory_stats(&self) -> MemoryStats {
+        memory_stats::stats()
+    }
+
+    /**
+     * Gives the linked version of the ENet library.
+     */
+    pub fn linked_version(&self) -> u32 {
+        unsafe { enet_linked_version() }
+    }
+}
+
+impl Clone for Enet {
+    fn clone(&self) -> Enet {
+        *HANDLES.lock().unwrap_or_else(|e| e.into_inner()) += 1;
+        Enet { _private: () }
+    }
+}
+
+impl Drop for Enet {
+    fn drop(&mut self) {
+        let mut handles = HANDLES.lock().unwrap_or_else(|e| e.into_inner());
+        *handles -= 1;
+        if *handles == 0 {
+            unsafe { enet_deinitialize() }
+
+            #[cfg(feature = "memory-stats")]
+            memory_stats::report_leaks();
+        }
+    }
+}
+
+#[cfg(not(feature = "memory-stats"))]
+unsafe fn initialize() -> c_int {
+    crate::enet::enet_initialize()
+}
+
+/** allocations are only seen through the callbacks */
+#[cfg(feature = "memory-stats")]
+unsafe fn initialize() -> c_int {
+    enet_initialize_with_callbacks(ENET_VERSION!(), &allocator::callbacks())
+}
+
+fn check_linked_version() -> Result<(), Error> {
+    let linked = unsafe { enet_linked_version() };
+    let expected = ENET_VERSION!();
+    if ENET_VERSION_GET_MAJOR!(linked) != ENET_VERSION_GET_MAJOR!(expected)
+        || ENET_VERSION_GET_MINOR!(linked) != ENET_VERSION_GET_MINOR!(expected)
+        || ENET_VERSION_GET_PATCH!(linked) != ENET_VERSION_GET_PATCH!(expected) {
+        return Err(Error::VersionMismatch { linked, expected });
+    }
+
+    Ok(())
+}
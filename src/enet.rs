@@ -9,7 +9,7 @@ use crate::{
     callbacks::ENetCallbacks,
     header::{ENetBuffer, ENetSocket, ENetSocketSet},
     list::{ENetListNode, ENetList},
-    protocol::ENetProtocol
+    protocol::{ENetProtocol, ENET_PROTOCOL_MAXIMUM_MTU, ENET_PROTOCOL_MAXIMUM_PACKET_COMMANDS}
 };
 
 use libc::{c_void, c_char, c_int, c_uint, size_t};
@@ -107,7 +107,7 @@ pub const ENET_PORT_ANY: c_uint = 0;
  * address is updated from ENET_HOST_BROADCAST to the server's actual IP address.
  */
 #[repr(C)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct ENetAddress {
     pub host: enet_uint32,
     pub port: enet_uint16,
@@ -227,7 +227,7 @@ pub struct ENetIncomingCommand {
 }
 
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum ENetPeerState {
     ENET_PEER_STATE_DISCONNECTED = 0,
     ENET_PEER_STATE_CONNECTING = 1,
@@ -244,7 +244,7 @@ pub enum ENetPeerState {
 #[macro_export]
 macro_rules! ENET_BUFFER_MAXIMUM {
     () => {
-       (1 + 2 * $crate::enet::protocol::ENET_PROTOCOL_MAXIMUM_PACKET_COMMANDS)
+       (1 + 2 * $crate::protocol::ENET_PROTOCOL_MAXIMUM_PACKET_COMMANDS)
     };
 }
 
@@ -312,7 +312,7 @@ pub struct ENetChannel {
     pub outgoingReliableSequenceNumber: enet_uint16,
     pub outgoingUnreliableSequenceNumber: enet_uint16,
     pub usedReliableWindows: enet_uint16,
-    pub reliableWindows: [enet_uint16; 16usize],
+    pub reliableWindows: [enet_uint16; ENET_PEER_RELIABLE_WINDOWS as usize],
     pub incomingReliableSequenceNumber: enet_uint16,
     pub incomingUnreliableSequenceNumber: enet_uint16,
     pub incomingReliableCommands: ENetList,
@@ -396,7 +396,7 @@ pub struct ENetPeer {
     pub reserved: enet_uint16,
     pub incomingUnsequencedGroup: enet_uint16,
     pub outgoingUnsequencedGroup: enet_uint16,
-    pub unsequencedWindow: [enet_uint32; 32usize],
+    pub unsequencedWindow: [enet_uint32; ENET_PEER_UNSEQUENCED_WINDOW_SIZE as usize / 32],
     pub eventData: enet_uint32,
     pub totalWaitingData: size_t,
 }
@@ -420,7 +420,7 @@ pub struct ENetCompressor {
 pub type ENetChecksumCallback = Option<unsafe extern "C" fn(buffers: *const ENetBuffer, bufferCount: size_t) -> enet_uint32>;
 
 /** Callback for intercepting received raw UDP packets. Should return 1 to intercept, 0 to ignore, or -1 to propagate an error. */
-pub type ENetInterceptCallback = Option<unsafe extern "C" fn(host: *mut ENetHost, event: *mut ENetHost) -> c_int>;
+pub type ENetInterceptCallback = Option<unsafe extern "C" fn(host: *mut ENetHost, event: *mut ENetEvent) -> c_int>;
 
 /**
  * An ENet host for communicating with peers.
@@ -486,14 +486,14 @@ pub struct ENetHost {
     pub continueSending: c_int,
     pub packetSize: size_t,
     pub headerFlags: enet_uint16,
-    pub commands: [ENetProtocol; 32usize],
+    pub commands: [ENetProtocol; ENET_PROTOCOL_MAXIMUM_PACKET_COMMANDS as usize],
     pub commandCount: size_t,
-    pub buffers: [ENetBuffer; 65usize],
+    pub buffers: [ENetBuffer; crate::ENET_BUFFER_MAXIMUM!() as usize],
     pub bufferCount: size_t,
     /** callback the user can set to enable packet checksums for this host */
     pub checksum: ENetChecksumCallback,
     pub compressor: ENetCompressor,
-    pub packetData: [[enet_uint8; 4096usize]; 2usize],
+    pub packetData: [[enet_uint8; ENET_PROTOCOL_MAXIMUM_MTU as usize]; 2],
     pub receivedAddress: ENetAddress,
     pub receivedData: *mut enet_uint8,
     pub receivedDataLength: size_t,
@@ -523,7 +523,7 @@ pub struct ENetHost {
  * [`ENetEvent`]: ENetEvent
  */
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum ENetEventType {
     /** no event occurred within the specified time limit */
     ENET_EVENT_TYPE_NONE       = 0,
@@ -630,14 +630,15 @@ extern "C" {
     pub fn enet_socket_get_address(socket: ENetSocket, address: *mut ENetAddress) -> c_int;
     pub fn enet_socket_listen(socket: ENetSocket, arg2: c_int) -> c_int;
     pub fn enet_socket_accept(socket: ENetSocket, address: *mut ENetAddress) -> ENetSocket;
+    pub fn enet_socket_connect(socket: ENetSocket, address: *const ENetAddress) -> c_int;
     pub fn enet_socket_send(socket: ENetSocket, address: *const ENetAddress, buffers: *const ENetBuffer, bufferCount: size_t) -> c_int;
-    pub fn enet_socket_receive(socket: ENetSocket, address: *const ENetAddress, buffers: *const ENetBuffer, bufferCount: size_t) -> c_int;
-    pub fn enet_socket_wait(socket: ENetSocket, condition: *const enet_uint32, timeout: enet_uint32) -> c_int;
+    pub fn enet_socket_receive(socket: ENetSocket, address: *mut ENetAddress, buffers: *mut ENetBuffer, bufferCount: size_t) -> c_int;
+    pub fn enet_socket_wait(socket: ENetSocket, condition: *mut enet_uint32, timeout: enet_uint32) -> c_int;
     pub fn enet_socket_set_option(socket: ENetSocket, option: ENetSocketOption, value: c_int) -> c_int;
-    pub fn enet_socket_get_option(socket: ENetSocket, option: ENetSocketOption, value: *const c_int) -> c_int;
+    pub fn enet_socket_get_option(socket: ENetSocket, option: ENetSocketOption, value: *mut c_int) -> c_int;
     pub fn enet_socket_shutdown(socket: ENetSocket, how: ENetSocketShutdown) -> c_int;
     pub fn enet_socket_destroy(socket: ENetSocket);
-    pub fn enet_socketset_select(maxSocket: ENetSocket, readSet: *const ENetSocketSet, writeSet: *const ENetSocketSet, timeout: enet_uint32) -> c_int;
+    pub fn enet_socketset_select(maxSocket: ENetSocket, readSet: *mut ENetSocketSet, writeSet: *mut ENetSocketSet, timeout: enet_uint32) -> c_int;
 
     /* Address ENet address functions */
 
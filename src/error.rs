@@ -0,0 +1,141 @@
+/*
+ * error.rs
+ *
+ * Errors returned by the safe ENet wrappers
+ */
+
+use std::{fmt, io};
+
+use crate::{ENET_VERSION_GET_MAJOR, ENET_VERSION_GET_MINOR, ENET_VERSION_GET_PATCH};
+
+/**
+ * An error returned by one of the safe ENet wrappers.
+ *
+ * The raw ENet functions only report failure through `-1`, `0` or a null
+ * pointer; each variant names the call that failed. Where the failure comes
+ * from the OS, the `io::Error` is captured right after the call, since ENet
+ * itself does not keep it.
+ */
+#[derive(Debug)]
+pub enum Error {
+    /** `enet_initialize` returned < 0 */
+    InitFailed,
+    /** the linked library is not compatible with these bindings */
+    VersionMismatch {
+        /** version reported by `enet_linked_version` */
+        linked: u32,
+        /** version the bindings were written for */
+        expected: u32,
+    },
+    /** an allocator can only be installed before ENet is first initialized */
+    AllocatorUnavailable,
+    /**
+     * `enet_host_create` returned null, with the OS error if the socket could
+     * not be created or bound, or None if ENet ran out of memory
+     */
+    HostCreateFailed(Option<io::Error>),
+    /** a host option is outside the range allowed by the protocol */
+    InvalidConfig {
+        /** name of the option */
+        option: &'static str,
+        /** rejected value */
+        value: usize,
+    },
+    /** installing a compressor on the host failed */
+    CompressorFailed,
+    /** no peer of the host has this index */
+    InvalidPeer(usize),
+    /** `enet_host_connect` found no free peer slot */
+    NoAvailablePeers,
+    /** `enet_host_service` or `enet_host_check_events` returned < 0 after a socket operation failed */
+    ServiceFailed(io::Error),
+    /** an intercept hook returned `InterceptAction::Error`, or panicked */
+    InterceptFailed,
+    /** `enet_packet_create` returned null */
+    PacketCreateFailed,
+    /** `enet_packet_resize` returned < 0, or the packet is shared */
+    PacketResizeFailed,
+    /** the packet exceeds the host's `maximumPacketSize` */
+    PacketTooLarge {
+        /** length of the packet */
+        size: usize,
+        /** maximum packet size of the host */
+        limit: usize,
+    },
+    /** the query response would not fit a single datagram */
+    QueryTooLarge {
+        /** length of the response */
+        size: usize,
+        /** maximum response size */
+        limit: usize,
+    },
+    /** an address could not be parsed, resolved or printed */
+    AddressResolution,
+    /** ENet only supports IPv4 addresses */
+    UnsupportedAddress,
+    /** `enet_peer_send` returned < 0 */
+    SendFailed {
+        /** channel the packet was queued on */
+        channel: u8,
+    },
+    /** an intercept hook emitted an event while the host was serviced without one, e.g. `enet_host_service` given a null event */
+    NoEventToEmit,
+    /** a socket operation failed */
+    Socket(io::Error),
+    /** the service thread of a `ThreadedHost` has stopped */
+    HostStopped,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InitFailed => f.write_str("failed to initialize ENet"),
+            Error::VersionMismatch { linked, expected } => write!(
+                f,
+                "linked ENet version {}.{}.{} is not compatible with {}.{}.{}",
+                ENET_VERSION_GET_MAJOR!(linked), ENET_VERSION_GET_MINOR!(linked), ENET_VERSION_GET_PATCH!(linked),
+                ENET_VERSION_GET_MAJOR!(expected), ENET_VERSION_GET_MINOR!(expected), ENET_VERSION_GET_PATCH!(expected),
+            ),
+            Error::AllocatorUnavailable => {
+                f.write_str("an allocator can only be installed before ENet is first initialized")
+            }
+            Error::HostCreateFailed(Some(e)) => write!(f, "failed to create ENet host: {}", e),
+            Error::HostCreateFailed(None) => f.write_str("failed to create ENet host: out of memory"),
+            Error::InvalidConfig { option, value } => write!(f, "invalid value {} for host option {}", value, option),
+            Error::CompressorFailed => f.write_str("failed to install ENet compressor"),
+            Error::InvalidPeer(id) => write!(f, "no peer with id {}", id),
+            Error::NoAvailablePeers => f.write_str("no available peers for initiating an ENet connection"),
+            Error::ServiceFailed(e) => write!(f, "failed to service ENet host: {}", e),
+            Error::InterceptFailed => f.write_str("the intercept hook of the ENet host failed"),
+            Error::PacketCreateFailed => f.write_str("failed to create ENet packet"),
+            Error::PacketResizeFailed => f.write_str("failed to resize ENet packet"),
+            Error::PacketTooLarge { size, limit } => {
+                write!(f, "packet of {} bytes exceeds the maximum packet size of {} bytes", size, limit)
+            }
+            Error::QueryTooLarge { size, limit } => {
+                write!(f, "query response of {} bytes exceeds the maximum of {} bytes", size, limit)
+            }
+            Error::AddressResolution => f.write_str("failed to resolve address"),
+            Error::UnsupportedAddress => f.write_str("ENet only supports IPv4 addresses"),
+            Error::SendFailed { channel } => write!(f, "failed to send packet on channel {}", channel),
+            Error::NoEventToEmit => f.write_str("the ENet host is not being serviced for an event"),
+            Error::Socket(e) => write!(f, "socket error: {}", e),
+            Error::HostStopped => f.write_str("the ENet service thread has stopped"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::HostCreateFailed(Some(e)) | Error::ServiceFailed(e) | Error::Socket(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Error {
+        Error::Socket(e)
+    }
+}
@@ -0,0 +1,143 @@
+/*
+ * event.rs
+ *
+ * Safe ENet event
+ */
+
+use std::ptr::NonNull;
+
+use crate::{
+    enet::{ENetEvent, ENetEventType},
+    packet::Packet,
+    peer::Peer,
+};
+
+/**
+ * An event as returned by [`Host::service`] and [`Host::check_events`].
+ *
+ * [`Host::service`]: crate::host::Host::service
+ * [`Host::check_events`]: crate::host::Host::check_events
+ */
+#[derive(Debug)]
+pub enum Event<'h, T = ()> {
+    /**
+     * a connection request initiated by [`Host::connect`] has completed, or a
+     * foreign host has connected to this one.
+     *
+     * [`Host::connect`]: crate::host::Host::connect
+     */
+    Connect {
+        /** peer which connected */
+        peer: Peer<'h, T>,
+        /** user data supplied by the connecting host */
+        data: u32,
+    },
+
+    /**
+     * a peer has disconnected, either on completion of a requested disconnect,
+     * because it timed out, or because a connection request timed out. The
+     * peer's user data is still available and is dropped on the next call
+     * into the host.
+     */
+    Disconnect {
+        /** peer which disconnected */
+        peer: Peer<'h, T>,
+        /** user supplied data describing the disconnection, or 0 */
+        data: u32,
+    },
+
+    /** a packet has been received from a peer */
+    Receive {
+        /** peer which sent the packet */
+        peer: Peer<'h, T>,
+        /** channel the packet was received on */
+        channel: u8,
+        /** the received packet, destroyed when dropped */
+        packet: Packet,
+    },
+}
+
+impl<'h, T> Event<'h, T> {
+    /**
+     * Converts a raw event filled in by ENet, taking ownership of its packet.
+     *
+     * # Safety
+     * `event` must have been filled in by a host that outlives `'h`.
+     */
+    pub(crate) unsafe fn from_raw(event: &ENetEvent) -> Option<Event<'h, T>> {
+        let peer = Peer::from_raw(NonNull::new(event.peer)?);
+        match event.type_ {
+            ENetEventType::ENET_EVENT_TYPE_NONE => None,
+            ENetEventType::ENET_EVENT_TYPE_CONNECT => Some(Event::Connect { peer, data: event.data }),
+            ENetEventType::ENET_EVENT_TYPE_DISCONNECT => Some(Event::Disconnect { peer, data: event.data }),
+            ENetEventType::ENET_EVENT_TYPE_RECEIVE => Some(Event::Receive {
+                peer,
+                channel: event.channelID,
+                packet: Packet::from_raw(event.packet)?,
+            }),
+        }
+    }
+
+    /** peer that generated the event */
+    pub fn peer(&self) -> &Peer<'h, T> {
+        match self {
+            Event::Connect { peer, .. } => peer,
+            Event::Disconnect { peer, .. } => peer,
+            Event::Receive { peer, .. } => peer,
+        }
+    }
+
+    /** peer that generated the event */
+    pub fn peer_mut(&mut self) -> &mut Peer<'h, T> {
+        match self {
+            Event::Connect { peer, .. } => peer,
+            Event::Disconnect { peer, .. } => peer,
+            Event::Receive { peer, .. } => peer,
+        }
+    }
+}
+
+/**
+ * An event detached from its host, naming the peer by its id, as yielded by
+ * streams of events that cannot borrow the host.
+ */
+#[derive(Debug)]
+pub enum OwnedEvent {
+    Connect {
+        /** id of the peer which connected */
+        peer: usize,
+        data: u32,
+    },
+    Disconnect {
+        /** id of the peer which disconnected */
+        peer: usize,
+        data: u32,
+    },
+    Receive {
+        /** id of the peer which sent the packet */
+        peer: usize,
+        channel: u8,
+        packet: Packet,
+    },
+}
+
+impl OwnedEvent {
+    /** id of the peer that generated the event */
+    pub fn peer_id(&self) -> usize {
+        match *self {
+            OwnedEvent::Connect { peer, .. } => peer,
+            OwnedEvent::Disconnect { peer, .. } => peer,
+            OwnedEvent::Receive { peer, .. } => peer,
+        }
+    }
+}
+
+impl<'h, T> From<Event<'h, T>> for OwnedEvent {
+    fn from(event: Event<'h, T>) -> OwnedEvent {
+        match event {
+            Event::Connect { peer, data } => OwnedEvent::Connect { peer: peer.id(), data },
+            Event::Disconnect { peer, data } => OwnedEvent::Disconnect { peer: peer.id(), data },
+            Event::Receive { peer, channel, packet } => OwnedEvent::Receive { peer: peer.id(), channel, packet },
+        }
+    }
+}
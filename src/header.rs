@@ -1,4 +1,4 @@
-use libc::{c_char, c_void, size_t};
+use libc::{c_void, size_t};
 
 use crate::{
     types::{SOCKET, INVALID_SOCKET},
@@ -13,7 +13,7 @@ use crate::{
 
 pub type ENetSocket = SOCKET;
 
-pub const ENET_SOCKET_NULL: c_char = INVALID_SOCKET;
+pub const ENET_SOCKET_NULL: ENetSocket = INVALID_SOCKET;
 
 /** macro that converts host to net byte-order of a 16-bit value */
 #[macro_export]
@@ -47,45 +47,152 @@ macro_rules! ENET_NET_TO_HOST_32 {
     };
 }
 
+/** ordered like `struct iovec`, as in unix.h */
+#[cfg(unix)]
 #[repr(C)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
+pub struct ENetBuffer {
+    pub data: *mut c_void,
+    pub dataLength: size_t,
+}
+
+/** ordered as in win32.h */
+#[cfg(windows)]
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
 pub struct ENetBuffer {
     pub dataLength: size_t,
     pub data: *mut c_void,
 }
 
+impl ENetBuffer {
+    /**
+     * Views the buffer as a byte slice.
+     *
+     * # Safety
+     * `data` must be null or valid for reads of `dataLength` bytes for `'a`.
+     */
+    pub unsafe fn as_slice<'a>(&self) -> &'a [u8] {
+        if self.data.is_null() {
+            &[]
+        } else {
+            std::slice::from_raw_parts(self.data as *const u8, self.dataLength)
+        }
+    }
+}
+
 pub type ENetSocketSet = fd_set;
 
 #[macro_export]
 macro_rules! ENET_SOCKETSET_EMPTY {
     ($sockset:expr) => {
-        // NOT TESTED!
-        libc::FD_ZERO(& ($sockset))
+        $crate::header::enet_socketset_empty(&mut ($sockset))
     };
 }
 
 #[macro_export]
 macro_rules! ENET_SOCKETSET_ADD {
     ($sockset:expr,$socket:expr) => {
-        // NOT TESTED!
-        libc::FD_SET($socket, & ($sockset))
+        $crate::header::enet_socketset_add(&mut ($sockset), $socket)
     };
 }
 
 #[macro_export]
 macro_rules! ENET_SOCKETSET_REMOVE {
     ($sockset:expr,$socket:expr) => {
-        // NOT TESTED!
-        libc::FD_CLR($socket, & ($sockset))
+        $crate::header::enet_socketset_remove(&mut ($sockset), $socket)
     };
 }
 
 #[macro_export]
 macro_rules! ENET_SOCKETSET_CHECK {
     ($sockset:expr,$socket:expr) => {
-        // NOT TESTED!
-        libc::FD_ISSET($socket, & ($sockset))
+        $crate::header::enet_socketset_check(&($sockset), $socket)
     };
 }
 
+/** FD_ZERO */
+#[cfg(unix)]
+pub fn enet_socketset_empty(sockset: &mut ENetSocketSet) {
+    unsafe { libc::FD_ZERO(sockset) }
+}
+
+/**
+ * FD_SET
+ *
+ * # Safety
+ * `socket` must be below `FD_SETSIZE`.
+ */
+#[cfg(unix)]
+pub unsafe fn enet_socketset_add(sockset: &mut ENetSocketSet, socket: ENetSocket) {
+    libc::FD_SET(socket, sockset)
+}
+
+/**
+ * FD_CLR
+ *
+ * # Safety
+ * `socket` must be below `FD_SETSIZE`.
+ */
+#[cfg(unix)]
+pub unsafe fn enet_socketset_remove(sockset: &mut ENetSocketSet, socket: ENetSocket) {
+    libc::FD_CLR(socket, sockset)
+}
+
+/**
+ * FD_ISSET
+ *
+ * # Safety
+ * `socket` must be below `FD_SETSIZE`.
+ */
+#[cfg(unix)]
+pub unsafe fn enet_socketset_check(sockset: &ENetSocketSet, socket: ENetSocket) -> bool {
+    libc::FD_ISSET(socket, sockset)
+}
+
+/** FD_ZERO */
+#[cfg(windows)]
+pub fn enet_socketset_empty(sockset: &mut ENetSocketSet) {
+    sockset.fd_count = 0;
+}
+
+/**
+ * FD_SET, which ignores sockets beyond `FD_SETSIZE` like Winsock does
+ *
+ * # Safety
+ * Always safe, unsafe to match the Unix definition.
+ */
+#[cfg(windows)]
+pub unsafe fn enet_socketset_add(sockset: &mut ENetSocketSet, socket: ENetSocket) {
+    let count = sockset.fd_count as usize;
+    if !sockset.fd_array[..count].contains(&socket) && count < sockset.fd_array.len() {
+        sockset.fd_array[count] = socket;
+        sockset.fd_count += 1;
+    }
+}
+
+/**
+ * FD_CLR
+ *
+ * # Safety
+ * Always safe, unsafe to match the Unix definition.
+ */
+#[cfg(windows)]
+pub unsafe fn enet_socketset_remove(sockset: &mut ENetSocketSet, socket: ENetSocket) {
+    let count = sockset.fd_count as usize;
+    if let Some(index) = sockset.fd_array[..count].iter().position(|&s| s == socket) {
+        sockset.fd_array.copy_within(index + 1..count, index);
+        sockset.fd_count -= 1;
+    }
+}
 
+/**
+ * FD_ISSET
+ *
+ * # Safety
+ * Always safe, unsafe to match the Unix definition.
+ */
+#[cfg(windows)]
+pub unsafe fn enet_socketset_check(sockset: &ENetSocketSet, socket: ENetSocket) -> bool {
+    sockset.fd_array[..sockset.fd_count as usize].contains(&socket)
+}
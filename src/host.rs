@@ -0,0 +1,482 @@
+/*
+ * host.rs
+ *
+ * Safe ENet host
+ */
+
+use std::{
+    io,
+    marker::PhantomData,
+    mem::MaybeUninit,
+    ptr::{self, NonNull},
+    slice,
+    time::Duration,
+};
+#[cfg(unix)]
+use std::os::unix::io::{AsRawFd, RawFd};
+
+use libc::c_int;
+#[cfg(all(feature = "mio", unix))]
+use mio::{event::Source, unix::SourceFd, Interest, Registry, Token};
+
+#[cfg(feature = "memory-stats")]
+use crate::memory_stats;
+use crate::{
+    allocator,
+    enet::{
+        ENetAddress,
+        ENetEvent,
+        ENetHost,
+        ENetPeer,
+        ENetPeerState,
+        ENET_HOST_BANDWIDTH_THROTTLE_INTERVAL,
+        enet_host_create,
+        enet_host_destroy,
+        enet_host_connect,
+        enet_host_service,
+        enet_host_check_events,
+        enet_host_flush,
+        enet_host_broadcast,
+        enet_host_compress,
+        enet_host_compress_with_range_coder,
+        enet_host_channel_limit,
+        enet_host_bandwidth_limit,
+        enet_time_get,
+    },
+    builder::HostBuilder,
+    checksum::{Checksum, Crc32},
+    compressor::{self, Compressor},
+    context::Enet,
+    error::Error,
+    event::Event,
+    header::ENetSocket,
+    intercept::{self, Intercept, InterceptAction, InterceptContext},
+    list::ENetList,
+    packet::Packet,
+    peer::{Peer, PeerState, Peers, take_peer_data},
+    protocol::ENET_PROTOCOL_MAXIMUM_PEER_ID,
+    query::QueryResponder,
+};
+
+/**
+ * An owned ENet host for communicating with peers.
+ *
+ * Created with [`enet_host_create`] and destroyed with [`enet_host_destroy`]
+ * when dropped. Peers handed out by the host borrow it, so they cannot be
+ * used after the host is gone.
+ *
+ * `T` is the type of the user data attached to each [`Peer`].
+ *
+ * The host keeps ENet initialized through its own [`Enet`] handle for as long
+ * as it lives.
+ *
+ * A host may be moved to another thread but never shared between threads,
+ * since ENet does no locking of its own.
+ *
+ * [`enet_host_create`]: crate::enet::enet_host_create
+ * [`enet_host_destroy`]: crate::enet::enet_host_destroy
+ */
+#[derive(Debug)]
+pub struct Host<T = ()> {
+    inner: NonNull<ENetHost>,
+    intercept: Option<Intercept>,
+    _enet: Enet,
+    _data: PhantomData<T>,
+}
+
+unsafe impl<T: Send> Send for Host<T> {}
+
+impl Host {
+    /**
+     * Creates a [`HostBuilder`] for configuring every tunable of the host.
+     */
+    pub fn builder() -> HostBuilder {
+        HostBuilder::new()
+    }
+}
+
+impl<T> Host<T> {
+    /**
+     * Creates a host for communicating to peers.
+     * ### Parameters
+     * ⠀⠀⠀⠀⠀*enet: handle keeping ENet initialized \
+     * ⠀⠀⠀⠀⠀address: the address at which other peers may connect to this host. If None, then no peers may connect to the host. \
+     * ⠀⠀⠀⠀⠀peer_count: the maximum number of peers that should be allocated for the host. \
+     * ⠀⠀⠀⠀⠀channel_limit: the maximum number of channels allowed; if 0, then this is equivalent to ENET_PROTOCOL_MAXIMUM_CHANNEL_COUNT \
+     * ⠀⠀⠀⠀⠀incoming_bandwidth: downstream bandwidth of the host in bytes/second; if 0, ENet will assume unlimited bandwidth. \
+     * ⠀⠀⠀⠀⠀outgoing_bandwidth: upstream bandwidth of the host in bytes/second; if 0, ENet will assume unlimited bandwidth.*
+     */
+    pub fn new(
+        enet: &Enet,
+        address: Option<&ENetAddress>,
+        peer_count: usize,
+        channel_limit: usize,
+        incoming_bandwidth: u32,
+        outgoing_bandwidth: u32,
+    ) -> Result<Host<T>, Error> {
+        // enet_host_create fails on this before allocating or opening anything
+        if peer_count > ENET_PROTOCOL_MAXIMUM_PEER_ID as usize {
+            return Err(Error::InvalidConfig { option: "peer_count", value: peer_count });
+        }
+
+        let address = address.map_or(ptr::null(), |address| address as *const _);
+        allocator::take_out_of_memory();
+        let host = unsafe {
+            enet_host_create(address, peer_count, channel_limit, incoming_bandwidth, outgoing_bandwidth)
+        };
+
+        #[cfg(feature = "memory-stats")]
+        if !host.is_null() {
+            memory_stats::record_peers(unsafe { (*host).peers });
+        }
+
+        NonNull::new(host)
+            .map(|inner| Host {
+                inner,
+                intercept: None,
+                _enet: enet.clone(),
+                _data: PhantomData,
+            })
+            .ok_or_else(|| {
+                // otherwise the socket could not be created or bound
+                let error = (!allocator::take_out_of_memory()).then(io::Error::last_os_error);
+                Error::HostCreateFailed(error)
+            })
+    }
+
+    pub fn as_raw(&self) -> *mut ENetHost {
+        self.inner.as_ptr()
+    }
+
+    /** the socket of the host, e.g. to wait on it in a `SocketSet` */
+    pub fn socket(&self) -> ENetSocket {
+        unsafe { self.inner.as_ref().socket }
+    }
+
+    /**
+     * Initiates a connection to a foreign host.
+     * ### Parameters
+     * ⠀⠀⠀⠀⠀*address: destination for the connection \
+     * ⠀⠀⠀⠀⠀channel_count: number of channels to allocate \
+     * ⠀⠀⠀⠀⠀data: user data supplied to the receiving host*
+     *
+     * The peer returned will have not completed the connection until
+     * [`Host::service`] notifies of a connect event for the peer.
+     */
+    pub fn connect(&mut self, address: &ENetAddress, channel_count: usize, data: u32) -> Result<Peer<'_, T>, Error> {
+        self.release_disconnected_data();
+        let peer = unsafe { enet_host_connect(self.inner.as_ptr(), address, channel_count, data) };
+        NonNull::new(peer)
+            .map(|peer| unsafe { Peer::from_raw(peer) })
+            .ok_or(Error::NoAvailablePeers)
+    }
+
+    /**
+     * Iterates over the peers of the host that are in use, i.e. not in the
+     * disconnected state.
+     */
+    pub fn peers(&mut self) -> Peers<'_, T> {
+        unsafe { Peers::new(self.inner, |state| state != PeerState::Disconnected) }
+    }
+
+    /**
+     * Iterates over the peers of the host that are fully connected.
+     */
+    pub fn connected_peers(&mut self) -> Peers<'_, T> {
+        unsafe { Peers::new(self.inner, |state| state == PeerState::Connected) }
+    }
+
+    /**
+     * Looks up a peer by its `incomingPeerID`, i.e. its index in the host.
+     */
+    pub fn peer_by_id(&mut self, id: usize) -> Option<Peer<'_, T>> {
+        if id < self.peer_count() {
+            Some(unsafe { Peer::new(self.inner, id) })
+        } else {
+            None
+        }
+    }
+
+    /** number of peers allocated for the host */
+    pub fn peer_count(&self) -> usize {
+        unsafe { self.inner.as_ref().peerCount }
+    }
+
+    /** number of peers currently connected to the host */
+    pub fn connected_count(&self) -> usize {
+        unsafe { self.inner.as_ref().connectedPeers }
+    }
+
+    /**
+     * Waits for events on the host and shuttles packets between the host and
+     * its peers.
+     * ### Parameters
+     * ⠀⠀⠀⠀⠀*timeout: number of milliseconds that ENet should wait for events*
+     */
+    pub fn service(&mut self, timeout: u32) -> Result<Option<Event<'_, T>>, Error> {
+        let event = self.service_raw(timeout)?;
+        Ok(event.and_then(|event| unsafe { Event::from_raw(&event) }))
+    }
+
+    /**
+     * Checks for any queued events on the host and dispatches one if
+     * available, without sending or receiving anything.
+     */
+    pub fn check_events(&mut self) -> Result<Option<Event<'_, T>>, Error> {
+        let event = self.check_events_raw()?;
+        Ok(event.and_then(|event| unsafe { Event::from_raw(&event) }))
+    }
+
+    /** [`Host::service`], giving the raw event so it does not borrow the host */
+    pub(crate) fn service_raw(&mut self, timeout: u32) -> Result<Option<ENetEvent>, Error> {
+        self.release_disconnected_data();
+        let mut event = MaybeUninit::uninit();
+        let result = unsafe { enet_host_service(self.inner.as_ptr(), event.as_mut_ptr(), timeout) };
+        take_event(result, event)
+    }
+
+    /** [`Host::check_events`], giving the raw event so it does not borrow the host */
+    pub(crate) fn check_events_raw(&mut self) -> Result<Option<ENetEvent>, Error> {
+        self.release_disconnected_data();
+        let mut event = MaybeUninit::uninit();
+        let result = unsafe { enet_host_check_events(self.inner.as_ptr(), event.as_mut_ptr()) };
+        take_event(result, event)
+    }
+
+    /**
+     * Drops the user data of peers that have disconnected since the last call,
+     * before ENet gets a chance to hand their slot to a new connection.
+     */
+    fn release_disconnected_data(&mut self) {
+        for peer in self.raw_peers() {
+            if peer.state == ENetPeerState::ENET_PEER_STATE_DISCONNECTED {
+                unsafe { take_peer_data::<T>(peer) };
+            }
+        }
+    }
+
+    fn raw_peers(&mut self) -> &mut [ENetPeer] {
+        unsafe {
+            let host = self.inner.as_ref();
+            slice::from_raw_parts_mut(host.peers, host.peerCount)
+        }
+    }
+
+    /**
+     * Computes how long the host can go without being serviced before ENet
+     * has to resend, ping or time out a peer, or None if no peer needs
+     * servicing until a datagram arrives. A duration of zero means the host
+     * has events or commands waiting and should be serviced right away.
+     *
+     * Packets queued while a reliable packet is awaiting acknowledgement do
+     * not shorten the timeout, as ENet may not be able to send them yet; call
+     * [`Host::flush`] after queueing packets, before waiting.
+     *
+     * Meant as the timeout of an event loop waiting on the host's socket
+     * alongside others.
+     */
+    pub fn next_timeout(&self) -> Option<Duration> {
+        let host = unsafe { self.inner.as_ref() };
+        if !list_is_empty(&host.dispatchQueue) {
+            return Some(Duration::from_millis(0));
+        }
+
+        let now = unsafe { enet_time_get() };
+        let mut deadline: Option<u32> = None;
+        let mut wait_until = |time: u32| {
+            // ENet times wrap around, compare them by difference to the current time
+            let remaining = (time.wrapping_sub(now) as i32).max(0) as u32;
+            deadline = Some(deadline.map_or(remaining, |deadline| deadline.min(remaining)));
+        };
+
+        if host.connectedPeers > 0 {
+            wait_until(host.bandwidthThrottleEpoch.wrapping_add(ENET_HOST_BANDWIDTH_THROTTLE_INTERVAL as u32));
+        }
+
+        let peers = unsafe { slice::from_raw_parts(host.peers, host.peerCount) };
+        for peer in peers {
+            if let ENetPeerState::ENET_PEER_STATE_DISCONNECTED | ENetPeerState::ENET_PEER_STATE_ZOMBIE = peer.state {
+                continue;
+            }
+
+            let awaiting_acknowledgement = !list_is_empty(&peer.sentReliableCommands);
+            if !list_is_empty(&peer.acknowledgements)
+                || (!list_is_empty(&peer.outgoingCommands) && !awaiting_acknowledgement) {
+                wait_until(now);
+            } else if awaiting_acknowledgement {
+                // resends and timeouts are both checked once nextTimeout passes
+                wait_until(peer.nextTimeout);
+            } else {
+                wait_until(peer.lastReceiveTime.wrapping_add(peer.pingInterval));
+            }
+        }
+
+        deadline.map(|deadline| Duration::from_millis(deadline.into()))
+    }
+
+    /**
+     * Sends any queued packets on the host to its designated peers.
+     */
+    pub fn flush(&mut self) {
+        unsafe { enet_host_flush(self.inner.as_ptr()) }
+    }
+
+    /**
+     * Queues a packet to be sent to all peers associated with the host.
+     * ### Parameters
+     * ⠀⠀⠀⠀⠀*channel_id: channel on which to broadcast \
+     * ⠀⠀⠀⠀⠀packet: packet to broadcast*
+     */
+    pub fn broadcast(&mut self, channel_id: u8, packet: Packet) {
+        // every peer the packet is queued on takes its own reference to it, and
+        // ENet destroys it if no peer did
+        unsafe { enet_host_broadcast(self.inner.as_ptr(), channel_id, packet.into_raw()) }
+    }
+
+    /**
+     * Enables packet checksums computed by `C`, replacing any previous
+     * checksum.
+     */
+    pub fn set_checksum<C: Checksum>(&mut self) {
+        unsafe { self.inner.as_mut().checksum = C::callback() }
+    }
+
+    /**
+     * Enables packet checksums with ENet's CRC32.
+     */
+    pub fn enable_crc32(&mut self) {
+        self.set_checksum::<Crc32>()
+    }
+
+    /**
+     * Disables packet checksums.
+     */
+    pub fn disable_checksum(&mut self) {
+        unsafe { self.inner.as_mut().checksum = None }
+    }
+
+    /**
+     * Compresses packets with a Rust compressor, replacing any previous
+     * compressor. The host owns the compressor and drops it when compression
+     * is changed or the host is destroyed.
+     */
+    pub fn set_compressor<C: Compressor + Send + 'static>(&mut self, compressor: C) {
+        let compressor = compressor::into_raw(compressor);
+        unsafe { enet_host_compress(self.inner.as_ptr(), &compressor) }
+    }
+
+    /**
+     * Compresses packets with ENet's built-in range coder.
+     */
+    pub fn compress_with_range_coder(&mut self) -> Result<(), Error> {
+        if unsafe { enet_host_compress_with_range_coder(self.inner.as_ptr()) } < 0 {
+            return Err(Error::CompressorFailed);
+        }
+
+        Ok(())
+    }
+
+    /**
+     * Disables packet compression, dropping the current compressor.
+     */
+    pub fn disable_compression(&mut self) {
+        unsafe { enet_host_compress(self.inner.as_ptr(), ptr::null()) }
+    }
+
+    /**
+     * Installs a hook that sees every raw UDP datagram received on the host's
+     * socket before ENet parses it, replacing any previous hook. The hook can
+     * swallow datagrams meant for other protocols sharing the port, and emit
+     * synthetic events.
+     */
+    pub fn set_intercept<F>(&mut self, hook: F)
+    where
+        F: FnMut(InterceptContext<'_>) -> InterceptAction + Send + 'static,
+    {
+        self.clear_intercept();
+        self.intercept = Some(unsafe { Intercept::install(self.inner.as_ptr(), Box::new(hook)) });
+    }
+
+    /**
+     * Answers server browser queries on the host's socket with `responder`,
+     * replacing any intercept hook.
+     */
+    pub fn set_query_responder(&mut self, responder: QueryResponder) {
+        self.set_intercept(move |context| responder.respond(&context));
+    }
+
+    /**
+     * Removes the intercept hook, if any.
+     */
+    pub fn clear_intercept(&mut self) {
+        unsafe { self.inner.as_mut().intercept = None }
+        self.intercept = None;
+    }
+
+    /**
+     * Limits the maximum allowed channels of future incoming connections.
+     * ### Parameters
+     * ⠀⠀⠀⠀⠀*channel_limit: the maximum number of channels allowed; if 0, then this is equivalent to ENET_PROTOCOL_MAXIMUM_CHANNEL_COUNT*
+     */
+    pub fn channel_limit(&mut self, channel_limit: usize) {
+        unsafe { enet_host_channel_limit(self.inner.as_ptr(), channel_limit) }
+    }
+
+    /**
+     * Adjusts the bandwidth limits of the host.
+     * ### Parameters
+     * ⠀⠀⠀⠀⠀*incoming_bandwidth: new incoming bandwidth \
+     * ⠀⠀⠀⠀⠀outgoing_bandwidth: new outgoing bandwidth*
+     */
+    pub fn bandwidth_limit(&mut self, incoming_bandwidth: u32, outgoing_bandwidth: u32) {
+        unsafe { enet_host_bandwidth_limit(self.inner.as_ptr(), incoming_bandwidth, outgoing_bandwidth) }
+    }
+}
+
+fn list_is_empty(list: &ENetList) -> bool {
+    ptr::eq(list.sentinel.next, &list.sentinel)
+}
+
+fn take_event(result: c_int, event: MaybeUninit<ENetEvent>) -> Result<Option<ENetEvent>, Error> {
+    match result {
+        r if r < 0 && intercept::take_failure() => Err(Error::InterceptFailed),
+        r if r < 0 => Err(Error::ServiceFailed(io::Error::last_os_error())),
+        0 => Ok(None),
+        _ => Ok(Some(unsafe { event.assume_init() })),
+    }
+}
+
+#[cfg(unix)]
+impl<T> AsRawFd for Host<T> {
+    fn as_raw_fd(&self) -> RawFd {
+        self.socket()
+    }
+}
+
+/** registers the host's socket, e.g. alongside other sockets of an event loop */
+#[cfg(all(feature = "mio", unix))]
+impl<T> Source for Host<T> {
+    fn register(&mut self, registry: &Registry, token: Token, interests: Interest) -> io::Result<()> {
+        SourceFd(&self.as_raw_fd()).register(registry, token, interests)
+    }
+
+    fn reregister(&mut self, registry: &Registry, token: Token, interests: Interest) -> io::Result<()> {
+        SourceFd(&self.as_raw_fd()).reregister(registry, token, interests)
+    }
+
+    fn deregister(&mut self, registry: &Registry) -> io::Result<()> {
+        SourceFd(&self.as_raw_fd()).deregister(registry)
+    }
+}
+
+impl<T> Drop for Host<T> {
+    fn drop(&mut self) {
+        for peer in self.raw_peers() {
+            unsafe { take_peer_data::<T>(peer) };
+        }
+
+        // unregisters the hook before the address of the host can be reused
+        self.clear_intercept();
+        unsafe { enet_host_destroy(self.inner.as_ptr()) }
+    }
+}
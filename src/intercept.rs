@@ -0,0 +1,253 @@
+/*
+ * intercept.rs
+ *
+ * Safe hook for intercepting raw UDP datagrams
+ */
+
+use std::{
+    cell::Cell,
+    fmt,
+    io,
+    marker::PhantomData,
+    panic::{self, AssertUnwindSafe},
+    ptr,
+    slice,
+    sync::{Mutex, MutexGuard},
+};
+
+use libc::{c_int, c_void};
+
+use crate::{
+    enet::{ENetAddress, ENetEvent, ENetEventType, ENetHost, enet_socket_send},
+    error::Error,
+    header::ENetBuffer,
+    packet::Packet,
+};
+
+/**
+ * What ENet should do with an intercepted datagram.
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum InterceptAction {
+    /** hand the datagram to the ENet protocol as usual */
+    Ignore,
+    /** the datagram has been handled, ENet skips it */
+    Intercept,
+    /** abort servicing the host, making it return an error */
+    Error,
+}
+
+/**
+ * An event emitted from an intercept hook, returned by `Host::service` as if
+ * ENet had produced it. Peers are given by their index in the host.
+ */
+#[derive(Debug)]
+pub enum SyntheticEvent {
+    Connect {
+        peer: usize,
+        data: u32,
+    },
+    Disconnect {
+        peer: usize,
+        data: u32,
+    },
+    Receive {
+        peer: usize,
+        channel: u8,
+        packet: Packet,
+    },
+}
+
+/**
+ * A datagram received on the host's socket, before ENet parses it.
+ *
+ * Built from `ENetHost.receivedAddress`, `receivedData` and
+ * `receivedDataLength` for the duration of the intercept hook.
+ */
+#[derive(Debug)]
+pub struct InterceptContext<'a> {
+    host: *mut ENetHost,
+    event: *mut ENetEvent,
+    _host: PhantomData<&'a mut ENetHost>,
+}
+
+impl<'a> InterceptContext<'a> {
+    /** address the datagram was received from */
+    pub fn sender(&self) -> ENetAddress {
+        unsafe { (*self.host).receivedAddress }
+    }
+
+    /** contents of the datagram */
+    pub fn data(&self) -> &'a [u8] {
+        unsafe {
+            let host = &*self.host;
+            if host.receivedData.is_null() {
+                &[]
+            } else {
+                slice::from_raw_parts(host.receivedData, host.receivedDataLength)
+            }
+        }
+    }
+
+    /** number of peers allocated for the host */
+    pub fn peer_count(&self) -> usize {
+        unsafe { (*self.host).peerCount }
+    }
+
+    /** number of peers currently connected to the host */
+    pub fn connected_count(&self) -> usize {
+        unsafe { (*self.host).connectedPeers }
+    }
+
+    /**
+     * Sends `data` as a single datagram from the host's socket back to the
+     * sender, bypassing the ENet protocol.
+     */
+    pub fn reply(&self, data: &[u8]) -> Result<(), Error> {
+        let buffer = ENetBuffer { dataLength: data.len(), data: data.as_ptr() as *mut c_void };
+        let address = self.sender();
+        match unsafe { enet_socket_send((*self.host).socket, &address, &buffer, 1) } {
+            sent if sent < 0 => Err(Error::Socket(io::Error::last_os_error())),
+            0 => Err(Error::Socket(io::ErrorKind::WouldBlock.into())),
+            _ => Ok(()),
+        }
+    }
+
+    /** the host that received the datagram */
+    pub fn host_raw(&self) -> *mut ENetHost {
+        self.host
+    }
+
+    /**
+     * Makes `Host::service` return `event` once the hook returns, replacing
+     * any event emitted before. The datagram is intercepted regardless of the
+     * returned action, unless it is [`InterceptAction::Error`].
+     */
+    pub fn emit(&mut self, event: SyntheticEvent) -> Result<(), Error> {
+        let (peer, type_) = match event {
+            SyntheticEvent::Connect { peer, .. } => (peer, ENetEventType::ENET_EVENT_TYPE_CONNECT),
+            SyntheticEvent::Disconnect { peer, .. } => (peer, ENetEventType::ENET_EVENT_TYPE_DISCONNECT),
+            SyntheticEvent::Receive { peer, .. } => (peer, ENetEventType::ENET_EVENT_TYPE_RECEIVE),
+        };
+
+        unsafe {
+            if self.event.is_null() {
+                return Err(Error::NoEventToEmit);
+            }
+            if peer >= (*self.host).peerCount {
+                return Err(Error::InvalidPeer(peer));
+            }
+
+            // a packet emitted before is owned by the event, and nothing else refers to it
+            let raw = &mut *self.event;
+            drop(Packet::from_raw(ptr::replace(&mut raw.packet, ptr::null_mut())));
+
+            raw.type_ = type_;
+            raw.peer = (*self.host).peers.add(peer);
+            raw.channelID = 0;
+            raw.data = 0;
+            match event {
+                SyntheticEvent::Connect { data, .. } | SyntheticEvent::Disconnect { data, .. } => raw.data = data,
+                SyntheticEvent::Receive { channel, packet, .. } => {
+                    raw.channelID = channel;
+                    raw.packet = packet.into_raw();
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub(crate) type Callback = dyn FnMut(InterceptContext<'_>) -> InterceptAction + Send;
+
+thread_local! {
+    /** whether a hook made the host being serviced on this thread fail */
+    static FAILED: Cell<bool> = const { Cell::new(false) };
+}
+
+/** hosts with an installed hook, as (host, callback) addresses */
+static HOOKS: Mutex<Vec<(usize, usize)>> = Mutex::new(Vec::new());
+
+/**
+ * An intercept hook registered for a host. `ENetInterceptCallback` carries no
+ * context, so the trampoline finds the hook by host address.
+ */
+pub(crate) struct Intercept {
+    host: usize,
+    callback: *mut Box<Callback>,
+}
+
+unsafe impl Send for Intercept {}
+
+impl fmt::Debug for Intercept {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Intercept").field("host", &self.host).finish()
+    }
+}
+
+impl Intercept {
+    /**
+     * Registers `callback` for `host` and points `ENetHost.intercept` at the
+     * trampoline.
+     *
+     * # Safety
+     * `host` must be valid, and the returned hook must be dropped before the
+     * host is used with another hook.
+     */
+    pub(crate) unsafe fn install(host: *mut ENetHost, callback: Box<Callback>) -> Intercept {
+        let callback = Box::into_raw(Box::new(callback));
+        hooks().push((host as usize, callback as usize));
+        (*host).intercept = Some(intercept);
+        Intercept { host: host as usize, callback }
+    }
+}
+
+impl Drop for Intercept {
+    fn drop(&mut self) {
+        // the host may already be destroyed, so only the registry is touched
+        hooks().retain(|&hook| hook != (self.host, self.callback as usize));
+        unsafe { drop(Box::from_raw(self.callback)) }
+    }
+}
+
+fn hooks() -> MutexGuard<'static, Vec<(usize, usize)>> {
+    HOOKS.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+unsafe extern "C" fn intercept(host: *mut ENetHost, event: *mut ENetEvent) -> c_int {
+    let callback = match hooks().iter().find(|&&(hook, _)| hook == host as usize) {
+        Some(&(_, callback)) => callback as *mut Box<Callback>,
+        None => return 0,
+    };
+
+    let context = InterceptContext { host, event, _host: PhantomData };
+    // a panic must not unwind into ENet, report it as an error instead
+    let action = panic::catch_unwind(AssertUnwindSafe(|| (*callback)(context)))
+        .unwrap_or(InterceptAction::Error);
+    let emitted = !event.is_null() && (*event).type_ != ENetEventType::ENET_EVENT_TYPE_NONE;
+
+    match action {
+        InterceptAction::Error => {
+            if emitted {
+                // ENet never reads the event once servicing fails, release what was emitted
+                drop(Packet::from_raw(ptr::replace(&mut (*event).packet, ptr::null_mut())));
+                (*event).type_ = ENetEventType::ENET_EVENT_TYPE_NONE;
+                (*event).peer = ptr::null_mut();
+            }
+
+            FAILED.with(|failed| failed.set(true));
+            -1
+        }
+        InterceptAction::Ignore if !emitted => 0,
+        _ => 1,
+    }
+}
+
+/**
+ * Whether a hook failed while servicing a host on this thread since the last
+ * call, as opposed to the socket.
+ */
+pub(crate) fn take_failure() -> bool {
+    FAILED.with(|failed| failed.replace(false))
+}
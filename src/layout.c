@@ -0,0 +1,320 @@
+/*
+ * layout.c
+ *
+ * sizes and offsets of the ENet C definitions, checked against the Rust ones by tests/layout.rs
+ */
+
+#include <stddef.h>
+#include <enet/enet.h>
+
+typedef struct
+{
+    const char * type;
+    const char * field;
+    size_t size;
+    size_t offset;
+} EnetRsLayout;
+
+#define TYPE(t) { #t, "", sizeof (t), 0 }
+#define FIELD(t, f) { #t, #f, sizeof (((t *) 0) -> f), offsetof (t, f) }
+
+const EnetRsLayout enet_rs_layouts [] =
+{
+    /* types.h, unix.h / win32.h */
+    TYPE (enet_uint8),
+    TYPE (enet_uint16),
+    TYPE (enet_uint32),
+    TYPE (ENetSocket),
+    TYPE (ENetSocketSet),
+
+    TYPE (ENetBuffer),
+    FIELD (ENetBuffer, data),
+    FIELD (ENetBuffer, dataLength),
+
+    /* list.h */
+    TYPE (ENetListNode),
+    FIELD (ENetListNode, next),
+    FIELD (ENetListNode, previous),
+
+    TYPE (ENetList),
+    FIELD (ENetList, sentinel),
+
+    /* callbacks.h */
+    TYPE (ENetCallbacks),
+    FIELD (ENetCallbacks, malloc),
+    FIELD (ENetCallbacks, free),
+    FIELD (ENetCallbacks, no_memory),
+
+    /* protocol.h */
+    TYPE (ENetProtocolHeader),
+    FIELD (ENetProtocolHeader, peerID),
+    FIELD (ENetProtocolHeader, sentTime),
+
+    TYPE (ENetProtocolCommandHeader),
+    FIELD (ENetProtocolCommandHeader, command),
+    FIELD (ENetProtocolCommandHeader, channelID),
+    FIELD (ENetProtocolCommandHeader, reliableSequenceNumber),
+
+    TYPE (ENetProtocolAcknowledge),
+    FIELD (ENetProtocolAcknowledge, header),
+    FIELD (ENetProtocolAcknowledge, receivedReliableSequenceNumber),
+    FIELD (ENetProtocolAcknowledge, receivedSentTime),
+
+    TYPE (ENetProtocolConnect),
+    FIELD (ENetProtocolConnect, header),
+    FIELD (ENetProtocolConnect, outgoingPeerID),
+    FIELD (ENetProtocolConnect, incomingSessionID),
+    FIELD (ENetProtocolConnect, outgoingSessionID),
+    FIELD (ENetProtocolConnect, mtu),
+    FIELD (ENetProtocolConnect, windowSize),
+    FIELD (ENetProtocolConnect, channelCount),
+    FIELD (ENetProtocolConnect, incomingBandwidth),
+    FIELD (ENetProtocolConnect, outgoingBandwidth),
+    FIELD (ENetProtocolConnect, packetThrottleInterval),
+    FIELD (ENetProtocolConnect, packetThrottleAcceleration),
+    FIELD (ENetProtocolConnect, packetThrottleDeceleration),
+    FIELD (ENetProtocolConnect, connectID),
+    FIELD (ENetProtocolConnect, data),
+
+    TYPE (ENetProtocolVerifyConnect),
+    FIELD (ENetProtocolVerifyConnect, header),
+    FIELD (ENetProtocolVerifyConnect, outgoingPeerID),
+    FIELD (ENetProtocolVerifyConnect, incomingSessionID),
+    FIELD (ENetProtocolVerifyConnect, outgoingSessionID),
+    FIELD (ENetProtocolVerifyConnect, mtu),
+    FIELD (ENetProtocolVerifyConnect, windowSize),
+    FIELD (ENetProtocolVerifyConnect, channelCount),
+    FIELD (ENetProtocolVerifyConnect, incomingBandwidth),
+    FIELD (ENetProtocolVerifyConnect, outgoingBandwidth),
+    FIELD (ENetProtocolVerifyConnect, packetThrottleInterval),
+    FIELD (ENetProtocolVerifyConnect, packetThrottleAcceleration),
+    FIELD (ENetProtocolVerifyConnect, packetThrottleDeceleration),
+    FIELD (ENetProtocolVerifyConnect, connectID),
+
+    TYPE (ENetProtocolBandwidthLimit),
+    FIELD (ENetProtocolBandwidthLimit, header),
+    FIELD (ENetProtocolBandwidthLimit, incomingBandwidth),
+    FIELD (ENetProtocolBandwidthLimit, outgoingBandwidth),
+
+    TYPE (ENetProtocolThrottleConfigure),
+    FIELD (ENetProtocolThrottleConfigure, header),
+    FIELD (ENetProtocolThrottleConfigure, packetThrottleInterval),
+    FIELD (ENetProtocolThrottleConfigure, packetThrottleAcceleration),
+    FIELD (ENetProtocolThrottleConfigure, packetThrottleDeceleration),
+
+    TYPE (ENetProtocolDisconnect),
+    FIELD (ENetProtocolDisconnect, header),
+    FIELD (ENetProtocolDisconnect, data),
+
+    TYPE (ENetProtocolPing),
+    FIELD (ENetProtocolPing, header),
+
+    TYPE (ENetProtocolSendReliable),
+    FIELD (ENetProtocolSendReliable, header),
+    FIELD (ENetProtocolSendReliable, dataLength),
+
+    TYPE (ENetProtocolSendUnreliable),
+    FIELD (ENetProtocolSendUnreliable, header),
+    FIELD (ENetProtocolSendUnreliable, unreliableSequenceNumber),
+    FIELD (ENetProtocolSendUnreliable, dataLength),
+
+    TYPE (ENetProtocolSendUnsequenced),
+    FIELD (ENetProtocolSendUnsequenced, header),
+    FIELD (ENetProtocolSendUnsequenced, unsequencedGroup),
+    FIELD (ENetProtocolSendUnsequenced, dataLength),
+
+    TYPE (ENetProtocolSendFragment),
+    FIELD (ENetProtocolSendFragment, header),
+    FIELD (ENetProtocolSendFragment, startSequenceNumber),
+    FIELD (ENetProtocolSendFragment, dataLength),
+    FIELD (ENetProtocolSendFragment, fragmentCount),
+    FIELD (ENetProtocolSendFragment, fragmentNumber),
+    FIELD (ENetProtocolSendFragment, totalLength),
+    FIELD (ENetProtocolSendFragment, fragmentOffset),
+
+    TYPE (ENetProtocol),
+    FIELD (ENetProtocol, header),
+    FIELD (ENetProtocol, acknowledge),
+    FIELD (ENetProtocol, connect),
+    FIELD (ENetProtocol, verifyConnect),
+    FIELD (ENetProtocol, disconnect),
+    FIELD (ENetProtocol, ping),
+    FIELD (ENetProtocol, sendReliable),
+    FIELD (ENetProtocol, sendUnreliable),
+    FIELD (ENetProtocol, sendUnsequenced),
+    FIELD (ENetProtocol, sendFragment),
+    FIELD (ENetProtocol, bandwidthLimit),
+    FIELD (ENetProtocol, throttleConfigure),
+
+    /* enet.h */
+    TYPE (ENetSocketType),
+    TYPE (ENetSocketOption),
+    TYPE (ENetSocketShutdown),
+    TYPE (ENetPeerState),
+    TYPE (ENetEventType),
+
+    TYPE (ENetAddress),
+    FIELD (ENetAddress, host),
+    FIELD (ENetAddress, port),
+
+    TYPE (ENetPacket),
+    FIELD (ENetPacket, referenceCount),
+    FIELD (ENetPacket, flags),
+    FIELD (ENetPacket, data),
+    FIELD (ENetPacket, dataLength),
+    FIELD (ENetPacket, freeCallback),
+    FIELD (ENetPacket, userData),
+
+    TYPE (ENetAcknowledgement),
+    FIELD (ENetAcknowledgement, acknowledgementList),
+    FIELD (ENetAcknowledgement, sentTime),
+    FIELD (ENetAcknowledgement, command),
+
+    TYPE (ENetOutgoingCommand),
+    FIELD (ENetOutgoingCommand, outgoingCommandList),
+    FIELD (ENetOutgoingCommand, reliableSequenceNumber),
+    FIELD (ENetOutgoingCommand, unreliableSequenceNumber),
+    FIELD (ENetOutgoingCommand, sentTime),
+    FIELD (ENetOutgoingCommand, roundTripTimeout),
+    FIELD (ENetOutgoingCommand, roundTripTimeoutLimit),
+    FIELD (ENetOutgoingCommand, fragmentOffset),
+    FIELD (ENetOutgoingCommand, fragmentLength),
+    FIELD (ENetOutgoingCommand, sendAttempts),
+    FIELD (ENetOutgoingCommand, command),
+    FIELD (ENetOutgoingCommand, packet),
+
+    TYPE (ENetIncomingCommand),
+    FIELD (ENetIncomingCommand, incomingCommandList),
+    FIELD (ENetIncomingCommand, reliableSequenceNumber),
+    FIELD (ENetIncomingCommand, unreliableSequenceNumber),
+    FIELD (ENetIncomingCommand, command),
+    FIELD (ENetIncomingCommand, fragmentCount),
+    FIELD (ENetIncomingCommand, fragmentsRemaining),
+    FIELD (ENetIncomingCommand, fragments),
+    FIELD (ENetIncomingCommand, packet),
+
+    TYPE (ENetChannel),
+    FIELD (ENetChannel, outgoingReliableSequenceNumber),
+    FIELD (ENetChannel, outgoingUnreliableSequenceNumber),
+    FIELD (ENetChannel, usedReliableWindows),
+    FIELD (ENetChannel, reliableWindows),
+    FIELD (ENetChannel, incomingReliableSequenceNumber),
+    FIELD (ENetChannel, incomingUnreliableSequenceNumber),
+    FIELD (ENetChannel, incomingReliableCommands),
+    FIELD (ENetChannel, incomingUnreliableCommands),
+
+    TYPE (ENetPeer),
+    FIELD (ENetPeer, dispatchList),
+    FIELD (ENetPeer, host),
+    FIELD (ENetPeer, outgoingPeerID),
+    FIELD (ENetPeer, incomingPeerID),
+    FIELD (ENetPeer, connectID),
+    FIELD (ENetPeer, outgoingSessionID),
+    FIELD (ENetPeer, incomingSessionID),
+    FIELD (ENetPeer, address),
+    FIELD (ENetPeer, data),
+    FIELD (ENetPeer, state),
+    FIELD (ENetPeer, channels),
+    FIELD (ENetPeer, channelCount),
+    FIELD (ENetPeer, incomingBandwidth),
+    FIELD (ENetPeer, outgoingBandwidth),
+    FIELD (ENetPeer, incomingBandwidthThrottleEpoch),
+    FIELD (ENetPeer, outgoingBandwidthThrottleEpoch),
+    FIELD (ENetPeer, incomingDataTotal),
+    FIELD (ENetPeer, outgoingDataTotal),
+    FIELD (ENetPeer, lastSendTime),
+    FIELD (ENetPeer, lastReceiveTime),
+    FIELD (ENetPeer, nextTimeout),
+    FIELD (ENetPeer, earliestTimeout),
+    FIELD (ENetPeer, packetLossEpoch),
+    FIELD (ENetPeer, packetsSent),
+    FIELD (ENetPeer, packetsLost),
+    FIELD (ENetPeer, packetLoss),
+    FIELD (ENetPeer, packetLossVariance),
+    FIELD (ENetPeer, packetThrottle),
+    FIELD (ENetPeer, packetThrottleLimit),
+    FIELD (ENetPeer, packetThrottleCounter),
+    FIELD (ENetPeer, packetThrottleEpoch),
+    FIELD (ENetPeer, packetThrottleAcceleration),
+    FIELD (ENetPeer, packetThrottleDeceleration),
+    FIELD (ENetPeer, packetThrottleInterval),
+    FIELD (ENetPeer, pingInterval),
+    FIELD (ENetPeer, timeoutLimit),
+    FIELD (ENetPeer, timeoutMinimum),
+    FIELD (ENetPeer, timeoutMaximum),
+    FIELD (ENetPeer, lastRoundTripTime),
+    FIELD (ENetPeer, lowestRoundTripTime),
+    FIELD (ENetPeer, lastRoundTripTimeVariance),
+    FIELD (ENetPeer, highestRoundTripTimeVariance),
+    FIELD (ENetPeer, roundTripTime),
+    FIELD (ENetPeer, roundTripTimeVariance),
+    FIELD (ENetPeer, mtu),
+    FIELD (ENetPeer, windowSize),
+    FIELD (ENetPeer, reliableDataInTransit),
+    FIELD (ENetPeer, outgoingReliableSequenceNumber),
+    FIELD (ENetPeer, acknowledgements),
+    FIELD (ENetPeer, sentReliableCommands),
+    FIELD (ENetPeer, sentUnreliableCommands),
+    FIELD (ENetPeer, outgoingCommands),
+    FIELD (ENetPeer, dispatchedCommands),
+    FIELD (ENetPeer, flags),
+    FIELD (ENetPeer, reserved),
+    FIELD (ENetPeer, incomingUnsequencedGroup),
+    FIELD (ENetPeer, outgoingUnsequencedGroup),
+    FIELD (ENetPeer, unsequencedWindow),
+    FIELD (ENetPeer, eventData),
+    FIELD (ENetPeer, totalWaitingData),
+
+    TYPE (ENetCompressor),
+    FIELD (ENetCompressor, context),
+    FIELD (ENetCompressor, compress),
+    FIELD (ENetCompressor, decompress),
+    FIELD (ENetCompressor, destroy),
+
+    TYPE (ENetHost),
+    FIELD (ENetHost, socket),
+    FIELD (ENetHost, address),
+    FIELD (ENetHost, incomingBandwidth),
+    FIELD (ENetHost, outgoingBandwidth),
+    FIELD (ENetHost, bandwidthThrottleEpoch),
+    FIELD (ENetHost, mtu),
+    FIELD (ENetHost, randomSeed),
+    FIELD (ENetHost, recalculateBandwidthLimits),
+    FIELD (ENetHost, peers),
+    FIELD (ENetHost, peerCount),
+    FIELD (ENetHost, channelLimit),
+    FIELD (ENetHost, serviceTime),
+    FIELD (ENetHost, dispatchQueue),
+    FIELD (ENetHost, continueSending),
+    FIELD (ENetHost, packetSize),
+    FIELD (ENetHost, headerFlags),
+    FIELD (ENetHost, commands),
+    FIELD (ENetHost, commandCount),
+    FIELD (ENetHost, buffers),
+    FIELD (ENetHost, bufferCount),
+    FIELD (ENetHost, checksum),
+    FIELD (ENetHost, compressor),
+    FIELD (ENetHost, packetData),
+    FIELD (ENetHost, receivedAddress),
+    FIELD (ENetHost, receivedData),
+    FIELD (ENetHost, receivedDataLength),
+    FIELD (ENetHost, totalSentData),
+    FIELD (ENetHost, totalSentPackets),
+    FIELD (ENetHost, totalReceivedData),
+    FIELD (ENetHost, totalReceivedPackets),
+    FIELD (ENetHost, intercept),
+    FIELD (ENetHost, connectedPeers),
+    FIELD (ENetHost, bandwidthLimitedPeers),
+    FIELD (ENetHost, duplicatePeers),
+    FIELD (ENetHost, maximumPacketSize),
+    FIELD (ENetHost, maximumWaitingData),
+
+    TYPE (ENetEvent),
+    FIELD (ENetEvent, type),
+    FIELD (ENetEvent, peer),
+    FIELD (ENetEvent, channelID),
+    FIELD (ENetEvent, data),
+    FIELD (ENetEvent, packet)
+};
+
+const size_t enet_rs_layout_count = sizeof (enet_rs_layouts) / sizeof (enet_rs_layouts [0]);
@@ -95,6 +95,36 @@
 //! }
 //! ```
 //!
+//! `safe_server.rs`, using the safe wrappers that handle initialization, destruction and packet ownership:
+//! ```rust,no_run
+//! use enet_rs::{Enet, Error, Event, Host};
+//! use enet_rs::enet::{ENET_HOST_ANY, ENetAddress};
+//!
+//! fn main() -> Result<(), Error> {
+//!     let enet = Enet::new()?;
+//!     let address = ENetAddress {
+//!         host: ENET_HOST_ANY,
+//!         port: 2555
+//!     };
+//!
+//!     let mut host: Host = Host::new(&enet, Some(&address), 32, 2, 0, 0)?;
+//!     loop {
+//!         match host.service(1000)? {
+//!             Some(Event::Connect { peer, .. }) => {
+//!                 println!("{:?} connected.", peer.address())
+//!             }
+//!             Some(Event::Receive { channel, packet, .. }) => {
+//!                 println!("received {} bytes on channel {}.", packet.data().len(), channel)
+//!             }
+//!             Some(Event::Disconnect { .. }) => {
+//!                 println!("disconnected.")
+//!             }
+//!             None => {}
+//!         }
+//!     }
+//! }
+//! ```
+//!
 //! ## Full Examples
 //! Full examples, detailing and explaining usage of the basic functionality of the library, can be found in the [`examples`] directory.
 //!
@@ -108,4 +138,46 @@ pub mod list;
 pub mod protocol;
 pub mod time;
 pub mod header;
-pub mod utility;
\ No newline at end of file
+pub mod utility;
+pub mod sys;
+
+pub mod address;
+pub mod allocator;
+#[cfg(all(feature = "tokio", unix))]
+pub mod async_host;
+pub mod builder;
+pub mod checksum;
+pub mod compressor;
+pub mod context;
+pub mod error;
+pub mod event;
+pub mod host;
+pub mod intercept;
+#[cfg(feature = "memory-stats")]
+pub mod memory_stats;
+pub mod packet;
+pub mod peer;
+pub mod query;
+pub mod range_coder;
+pub mod socket_set;
+pub mod threaded;
+
+pub use crate::{
+    allocator::{EnetAllocator, RustAllocator},
+    builder::HostBuilder,
+    checksum::Checksum,
+    compressor::Compressor,
+    context::Enet,
+    error::Error,
+    event::{Event, OwnedEvent},
+    host::Host,
+    intercept::{InterceptAction, InterceptContext, SyntheticEvent},
+    packet::Packet,
+    peer::{Peer, PeerState, Peers},
+    query::QueryResponder,
+    range_coder::RangeCoder,
+    socket_set::SocketSet,
+    threaded::{HostHandle, ThreadedConfig, ThreadedEvent, ThreadedHost, ThreadedPeer},
+};
+#[cfg(all(feature = "tokio", unix))]
+pub use crate::async_host::AsyncHost;
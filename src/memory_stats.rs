@@ -0,0 +1,152 @@
+/*
+ * memory_stats.rs
+ *
+ * Tracking of live ENet allocations, for finding leaked packets
+ */
+
+use std::{
+    collections::HashMap,
+    mem,
+    sync::{Mutex, MutexGuard},
+};
+
+use libc::c_void;
+
+use crate::enet::{ENetAcknowledgement, ENetHost, ENetIncomingCommand, ENetOutgoingCommand, ENetPacket, ENetPeer};
+
+/**
+ * What an ENet allocation was made for.
+ *
+ * `ENetCallbacks.malloc` is only given a size, so allocations are classified
+ * by matching it against the sizes of ENet's structures. Packet data and
+ * other buffers of exactly such a size are misattributed. Peer arrays vary in
+ * size, so they are recognized by address once a [`Host`] has been created.
+ *
+ * [`Host`]: crate::host::Host
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AllocationCategory {
+    /** an `ENetPacket`, not counting its data */
+    Packet,
+    /** the peer array of a host */
+    Peers,
+    /** an outgoing or incoming command, or an acknowledgement */
+    Command,
+    /** an `ENetHost` */
+    Host,
+    /** packet data and everything else */
+    Other,
+}
+
+impl AllocationCategory {
+    const ALL: [AllocationCategory; 5] = [
+        AllocationCategory::Packet,
+        AllocationCategory::Peers,
+        AllocationCategory::Command,
+        AllocationCategory::Host,
+        AllocationCategory::Other,
+    ];
+
+    fn of(size: usize) -> AllocationCategory {
+        if size == mem::size_of::<ENetPacket>() {
+            AllocationCategory::Packet
+        } else if size == mem::size_of::<ENetOutgoingCommand>()
+            || size == mem::size_of::<ENetIncomingCommand>()
+            || size == mem::size_of::<ENetAcknowledgement>() {
+            AllocationCategory::Command
+        } else if size == mem::size_of::<ENetHost>() {
+            AllocationCategory::Host
+        } else {
+            AllocationCategory::Other
+        }
+    }
+}
+
+/** number and total size of live allocations */
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct AllocationStats {
+    pub count: usize,
+    pub bytes: usize,
+}
+
+/**
+ * Live ENet allocations by category, returned by [`Enet::memory_stats`].
+ *
+ * [`Enet::memory_stats`]: crate::context::Enet::memory_stats
+ */
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct MemoryStats {
+    categories: HashMap<AllocationCategory, AllocationStats>,
+}
+
+impl MemoryStats {
+    /** live allocations in `category` */
+    pub fn category(&self, category: AllocationCategory) -> AllocationStats {
+        self.categories.get(&category).copied().unwrap_or_default()
+    }
+
+    /** all live allocations */
+    pub fn total(&self) -> AllocationStats {
+        self.categories.values().fold(AllocationStats::default(), |total, stats| AllocationStats {
+            count: total.count + stats.count,
+            bytes: total.bytes + stats.bytes,
+        })
+    }
+}
+
+/** sizes and categories of live allocations by address */
+static LIVE: Mutex<Option<HashMap<usize, (usize, AllocationCategory)>>> = Mutex::new(None);
+
+fn live() -> MutexGuard<'static, Option<HashMap<usize, (usize, AllocationCategory)>>> {
+    LIVE.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+pub(crate) fn record_alloc(memory: *mut c_void, size: usize) {
+    if !memory.is_null() {
+        live().get_or_insert_with(HashMap::new).insert(memory as usize, (size, AllocationCategory::of(size)));
+    }
+}
+
+/** files the peer array of a newly created host under `Peers` */
+pub(crate) fn record_peers(peers: *mut ENetPeer) {
+    if let Some((_, category)) = live().as_mut().and_then(|live| live.get_mut(&(peers as usize))) {
+        *category = AllocationCategory::Peers;
+    }
+}
+
+pub(crate) fn record_free(memory: *mut c_void) {
+    if let Some(live) = live().as_mut() {
+        live.remove(&(memory as usize));
+    }
+}
+
+pub(crate) fn stats() -> MemoryStats {
+    let mut stats = MemoryStats::default();
+    for &(size, category) in live().iter().flat_map(|live| live.values()) {
+        let category = stats.categories.entry(category).or_default();
+        category.count += 1;
+        category.bytes += size;
+    }
+
+    stats
+}
+
+/**
+ * Prints the allocations still live when ENet is deinitialized to stderr,
+ * e.g. packets received and never dropped.
+ */
+pub(crate) fn report_leaks() {
+    let stats = stats();
+    let total = stats.total();
+    if total.count == 0 {
+        return;
+    }
+
+    eprintln!("enet-rs: {} allocations ({} bytes) leaked at deinitialization", total.count, total.bytes);
+    for &category in AllocationCategory::ALL.iter() {
+        let leaked = stats.category(category);
+        if leaked.count > 0 {
+            eprintln!("enet-rs:     {:?}: {} allocations ({} bytes)", category, leaked.count, leaked.bytes);
+        }
+    }
+}
@@ -0,0 +1,246 @@
+/*
+ * packet.rs
+ *
+ * Safe ENet packet
+ */
+
+use std::{
+    mem,
+    ops::Deref,
+    ptr::{self, NonNull},
+    slice,
+};
+
+#[cfg(feature = "bytes")]
+use bytes::Bytes;
+use libc::c_void;
+
+use crate::{
+    enet::{
+        ENetPacket,
+        ENetPacketFlag,
+        enet_packet_create,
+        enet_packet_destroy,
+        enet_packet_resize,
+    },
+    context::Enet,
+    error::Error,
+};
+
+/**
+ * An owned ENet packet.
+ *
+ * A `Packet` holds one of the packet's `referenceCount` references, the same
+ * way every queued command inside ENet does, and dropping it releases it; the
+ * packet is destroyed with [`enet_packet_destroy`] once neither Rust nor ENet
+ * refers to it anymore. [`Peer::send`] and [`Host::broadcast`] hand Rust's
+ * reference over to ENet, so a packet is never shared between Rust and a
+ * host, whose thread may decrement the non-atomic count at any time. To send
+ * the same buffer several times without copying, create a packet per send
+ * with `Packet::from_bytes` from clones of one `Bytes`.
+ *
+ * Creating a packet takes an [`Enet`] handle, so that ENet is initialized, and
+ * any allocator given to [`Enet::with_allocator`] installed, before ENet
+ * allocates the packet it will later free.
+ *
+ * [`enet_packet_destroy`]: crate::enet::enet_packet_destroy
+ * [`Enet`]: crate::context::Enet
+ * [`Enet::with_allocator`]: crate::context::Enet::with_allocator
+ * [`Peer::send`]: crate::peer::Peer::send
+ * [`Host::broadcast`]: crate::host::Host::broadcast
+ */
+#[derive(Debug)]
+pub struct Packet {
+    inner: NonNull<ENetPacket>,
+}
+
+impl Packet {
+    /**
+     * Creates a packet holding a copy of `data`.
+     *
+     * `ENET_PACKET_FLAG_NO_ALLOCATE` is ignored, since the packet would point
+     * into `data` past its lifetime; see [`Packet::from_vec`] instead.
+     * ### Parameters
+     * ⠀⠀⠀⠀⠀*enet: handle keeping ENet initialized \
+     * ⠀⠀⠀⠀⠀data: initial contents of the packet \
+     * ⠀⠀⠀⠀⠀flags: bitwise-or of ENetPacketFlag constants*
+     */
+    pub fn new(_enet: &Enet, data: &[u8], flags: u32) -> Result<Packet, Error> {
+        let flags = flags & !(ENetPacketFlag::ENET_PACKET_FLAG_NO_ALLOCATE as u32);
+        let packet = unsafe { enet_packet_create(data.as_ptr() as *const _, data.len(), flags) };
+        unsafe { Packet::from_raw(packet) }.ok_or(Error::PacketCreateFailed)
+    }
+
+    /**
+     * Creates a packet that must be received by the target peer, resending it
+     * until it is delivered.
+     */
+    pub fn reliable(enet: &Enet, data: &[u8]) -> Result<Packet, Error> {
+        Packet::new(enet, data, ENetPacketFlag::ENET_PACKET_FLAG_RELIABLE as u32)
+    }
+
+    /**
+     * Creates a packet that may be lost, but is sequenced with the other
+     * packets sent on its channel.
+     */
+    pub fn unreliable(enet: &Enet, data: &[u8]) -> Result<Packet, Error> {
+        Packet::new(enet, data, 0)
+    }
+
+    /**
+     * Creates a packet that may be lost and is not sequenced with other
+     * packets.
+     */
+    pub fn unsequenced(enet: &Enet, data: &[u8]) -> Result<Packet, Error> {
+        Packet::new(enet, data, ENetPacketFlag::ENET_PACKET_FLAG_UNSEQUENCED as u32)
+    }
+
+    /**
+     * Creates a packet sending `data` in place, without copying it.
+     *
+     * The vector is kept alive until ENet is done with the packet, i.e. until
+     * it has been dropped and every peer it was sent to has released it.
+     * ### Parameters
+     * ⠀⠀⠀⠀⠀*enet: handle keeping ENet initialized \
+     * ⠀⠀⠀⠀⠀data: contents of the packet \
+     * ⠀⠀⠀⠀⠀flags: bitwise-or of ENetPacketFlag constants*
+     */
+    pub fn from_vec(_enet: &Enet, data: Vec<u8>, flags: u32) -> Result<Packet, Error> {
+        Packet::from_owner(data, flags)
+    }
+
+    /**
+     * Creates a packet sending `data` in place, without copying it.
+     *
+     * See [`Packet::from_vec`].
+     */
+    #[cfg(feature = "bytes")]
+    pub fn from_bytes(_enet: &Enet, data: Bytes, flags: u32) -> Result<Packet, Error> {
+        Packet::from_owner(data, flags)
+    }
+
+    /**
+     * Creates an `ENET_PACKET_FLAG_NO_ALLOCATE` packet pointing into `owner`,
+     * which is stashed in `userData` and dropped from the packet's
+     * `freeCallback`.
+     */
+    fn from_owner<B: AsRef<[u8]> + 'static>(owner: B, flags: u32) -> Result<Packet, Error> {
+        let owner = Box::new(owner);
+        let data = (*owner).as_ref();
+        let flags = flags | ENetPacketFlag::ENET_PACKET_FLAG_NO_ALLOCATE as u32;
+        unsafe {
+            let mut packet = Packet::from_raw(enet_packet_create(data.as_ptr() as *const _, data.len(), flags))
+                .ok_or(Error::PacketCreateFailed)?;
+            let raw = packet.inner.as_mut();
+            raw.userData = Box::into_raw(owner) as *mut c_void;
+            raw.freeCallback = Some(free_owner::<B>);
+            Ok(packet)
+        }
+    }
+
+    /**
+     * Takes a reference to a raw packet.
+     *
+     * # Safety
+     * `packet` must be null or a valid packet created by ENet. If nothing
+     * else refers to it (`referenceCount` is 0), the returned `Packet` becomes
+     * its sole owner.
+     */
+    pub unsafe fn from_raw(packet: *mut ENetPacket) -> Option<Packet> {
+        let mut inner = NonNull::new(packet)?;
+        inner.as_mut().referenceCount += 1;
+        Some(Packet { inner })
+    }
+
+    /**
+     * Releases this reference to the packet without destroying it, returning
+     * the raw pointer. If it was the last reference, the caller becomes
+     * responsible for the packet, as if it came from [`enet_packet_create`].
+     *
+     * [`enet_packet_create`]: crate::enet::enet_packet_create
+     */
+    pub fn into_raw(self) -> *mut ENetPacket {
+        let mut inner = self.inner;
+        mem::forget(self);
+        unsafe { inner.as_mut().referenceCount -= 1 };
+        inner.as_ptr()
+    }
+
+    pub fn as_raw(&self) -> *mut ENetPacket {
+        self.inner.as_ptr()
+    }
+
+    /** bitwise-or of ENetPacketFlag constants */
+    pub fn flags(&self) -> u32 {
+        unsafe { self.inner.as_ref().flag }
+    }
+
+    /** whether this is the only reference to the packet */
+    pub fn is_unique(&self) -> bool {
+        unsafe { self.inner.as_ref().referenceCount == 1 }
+    }
+
+    /** contents of the packet */
+    pub fn data(&self) -> &[u8] {
+        unsafe {
+            let packet = self.inner.as_ref();
+            if packet.data.is_null() {
+                &[]
+            } else {
+                slice::from_raw_parts(packet.data, packet.dataLength)
+            }
+        }
+    }
+
+    /**
+     * Attempts to resize the data in the packet to `length` bytes.
+     *
+     * Fails if the packet is still referenced elsewhere, e.g. queued for
+     * sending, since ENet reads the data in place when it goes out. Packets
+     * sending a buffer in place, e.g. from [`Packet::from_vec`], cannot grow,
+     * as ENet only changes their length without reallocating.
+     */
+    pub fn resize(&mut self, length: usize) -> Result<(), Error> {
+        let in_place = self.flags() & ENetPacketFlag::ENET_PACKET_FLAG_NO_ALLOCATE as u32 != 0;
+        if !self.is_unique() || (in_place && length > self.len()) {
+            return Err(Error::PacketResizeFailed);
+        }
+
+        if unsafe { enet_packet_resize(self.inner.as_ptr(), length) } < 0 {
+            return Err(Error::PacketResizeFailed);
+        }
+
+        Ok(())
+    }
+}
+
+unsafe extern "C" fn free_owner<B>(packet: *mut ENetPacket) {
+    drop(Box::from_raw((*packet).userData as *mut B));
+    (*packet).userData = ptr::null_mut();
+}
+
+impl Deref for Packet {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.data()
+    }
+}
+
+impl AsRef<[u8]> for Packet {
+    fn as_ref(&self) -> &[u8] {
+        self.data()
+    }
+}
+
+impl Drop for Packet {
+    fn drop(&mut self) {
+        unsafe {
+            let packet = self.inner.as_mut();
+            packet.referenceCount -= 1;
+            if packet.referenceCount == 0 {
+                enet_packet_destroy(packet);
+            }
+        }
+    }
+}
@@ -0,0 +1,307 @@
+/*
+ * peer.rs
+ *
+ * Safe ENet peer
+ */
+
+use std::{
+    marker::PhantomData,
+    ops::Range,
+    ptr::{self, NonNull},
+};
+
+use crate::{
+    enet::{
+        ENetAddress,
+        ENetHost,
+        ENetPeer,
+        ENetPeerState,
+        enet_peer_send,
+        enet_peer_receive,
+        enet_peer_ping,
+        enet_peer_reset,
+        enet_peer_disconnect,
+        enet_peer_disconnect_now,
+        enet_peer_disconnect_later,
+    },
+    error::Error,
+    host::Host,
+    packet::Packet,
+};
+
+/**
+ * State of a peer, as tracked by ENet in `ENetPeer.state`.
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PeerState {
+    Disconnected,
+    Connecting,
+    AcknowledgingConnect,
+    ConnectionPending,
+    ConnectionSucceeded,
+    Connected,
+    DisconnectLater,
+    Disconnecting,
+    AcknowledgingDisconnect,
+    Zombie,
+}
+
+impl From<ENetPeerState> for PeerState {
+    fn from(state: ENetPeerState) -> PeerState {
+        match state {
+            ENetPeerState::ENET_PEER_STATE_DISCONNECTED => PeerState::Disconnected,
+            ENetPeerState::ENET_PEER_STATE_CONNECTING => PeerState::Connecting,
+            ENetPeerState::ENET_PEER_STATE_ACKNOWLEDGING_CONNECT => PeerState::AcknowledgingConnect,
+            ENetPeerState::ENET_PEER_STATE_CONNECTION_PENDING => PeerState::ConnectionPending,
+            ENetPeerState::ENET_PEER_STATE_CONNECTION_SUCCEEDED => PeerState::ConnectionSucceeded,
+            ENetPeerState::ENET_PEER_STATE_CONNECTED => PeerState::Connected,
+            ENetPeerState::ENET_PEER_STATE_DISCONNECT_LATER => PeerState::DisconnectLater,
+            ENetPeerState::ENET_PEER_STATE_DISCONNECTING => PeerState::Disconnecting,
+            ENetPeerState::ENET_PEER_STATE_ACKNOWLEDGING_DISCONNECT => PeerState::AcknowledgingDisconnect,
+            ENetPeerState::ENET_PEER_STATE_ZOMBIE => PeerState::Zombie,
+        }
+    }
+}
+
+/**
+ * A peer of a [`Host`], addressed by its index into `ENetHost.peers`.
+ *
+ * The handle borrows the host it belongs to, so it cannot outlive the
+ * [`enet_host_destroy`] call that frees the peer.
+ *
+ * Each peer has a slot for user data of type `T`, stored in `ENetPeer.data`.
+ * The data is dropped when the peer is reset, once its disconnect event has
+ * been handled, or when the host is dropped.
+ *
+ * [`Host`]: crate::host::Host
+ * [`enet_host_destroy`]: crate::enet::enet_host_destroy
+ */
+#[derive(Debug)]
+pub struct Peer<'h, T = ()> {
+    host: NonNull<ENetHost>,
+    index: usize,
+    _host: PhantomData<&'h mut Host<T>>,
+}
+
+impl<'h, T> Peer<'h, T> {
+    /**
+     * # Safety
+     * `host` must outlive `'h` and have more than `index` peers.
+     */
+    pub(crate) unsafe fn new(host: NonNull<ENetHost>, index: usize) -> Peer<'h, T> {
+        Peer { host, index, _host: PhantomData }
+    }
+
+    /**
+     * # Safety
+     * `peer` must point into the peer array of a host that outlives `'h`.
+     */
+    pub(crate) unsafe fn from_raw(peer: NonNull<ENetPeer>) -> Peer<'h, T> {
+        let host = NonNull::new_unchecked(peer.as_ref().host);
+        let index = peer.as_ptr().offset_from(host.as_ref().peers) as usize;
+        Peer::new(host, index)
+    }
+
+    pub fn as_raw(&self) -> *mut ENetPeer {
+        unsafe { self.host.as_ref().peers.add(self.index) }
+    }
+
+    fn inner(&self) -> &ENetPeer {
+        unsafe { &*self.as_raw() }
+    }
+
+    /** index of the peer in the host, also its `incomingPeerID` */
+    pub fn id(&self) -> usize {
+        self.index
+    }
+
+    /**
+     * `connectID` of the current connection, which tells connections apart
+     * when the peer is reused
+     */
+    pub fn connect_id(&self) -> u32 {
+        self.inner().connectID
+    }
+
+    /** Internet address of the peer */
+    pub fn address(&self) -> ENetAddress {
+        self.inner().address
+    }
+
+    pub fn state(&self) -> PeerState {
+        self.inner().state.into()
+    }
+
+    /** mean round trip time (RTT), in milliseconds */
+    pub fn round_trip_time(&self) -> u32 {
+        self.inner().roundTripTime
+    }
+
+    /** user data attached to the peer */
+    pub fn data(&self) -> Option<&T> {
+        unsafe { (self.inner().data as *const T).as_ref() }
+    }
+
+    /** user data attached to the peer */
+    pub fn data_mut(&mut self) -> Option<&mut T> {
+        unsafe { ((*self.as_raw()).data as *mut T).as_mut() }
+    }
+
+    /**
+     * Attaches user data to the peer, dropping any previous data.
+     */
+    pub fn set_data(&mut self, data: T) {
+        self.take_data();
+        unsafe { (*self.as_raw()).data = Box::into_raw(Box::new(data)) as *mut _ }
+    }
+
+    /**
+     * Detaches the user data from the peer.
+     */
+    pub fn take_data(&mut self) -> Option<T> {
+        unsafe { take_peer_data(self.as_raw()) }
+    }
+
+    /**
+     * Queues a packet to be sent to the peer.
+     * ### Parameters
+     * ⠀⠀⠀⠀⠀*channel_id: channel on which to send \
+     * ⠀⠀⠀⠀⠀packet: packet to send*
+     */
+    pub fn send(&mut self, channel_id: u8, packet: Packet) -> Result<(), Error> {
+        let limit = unsafe { self.host.as_ref().maximumPacketSize };
+        if packet.len() > limit {
+            return Err(Error::PacketTooLarge { size: packet.len(), limit });
+        }
+
+        if unsafe { enet_peer_send(self.as_raw(), channel_id, packet.as_raw()) } < 0 {
+            return Err(Error::SendFailed { channel: channel_id });
+        }
+
+        // the queued command holds its own reference, leaving the packet to ENet
+        packet.into_raw();
+        Ok(())
+    }
+
+    /**
+     * Attempts to dequeue any incoming queued packet, along with the channel
+     * it was received on.
+     */
+    pub fn receive(&mut self) -> Option<(u8, Packet)> {
+        let mut channel_id = 0;
+        let packet = unsafe { enet_peer_receive(self.as_raw(), &mut channel_id) };
+        unsafe { Packet::from_raw(packet) }.map(|packet| (channel_id, packet))
+    }
+
+    /**
+     * Sends a ping request to the peer. Pings are sent automatically at
+     * regular intervals, this forces one out immediately.
+     */
+    pub fn ping(&mut self) {
+        unsafe { enet_peer_ping(self.as_raw()) }
+    }
+
+    /**
+     * Requests a disconnection from the peer. A disconnect event is generated
+     * once the disconnection is complete.
+     * ### Parameters
+     * ⠀⠀⠀⠀⠀*data: data describing the disconnection*
+     */
+    pub fn disconnect(&mut self, data: u32) {
+        unsafe { enet_peer_disconnect(self.as_raw(), data) }
+    }
+
+    /**
+     * Forcefully disconnects the peer. The foreign host is notified, but no
+     * disconnect event is generated and the user data is dropped right away.
+     * ### Parameters
+     * ⠀⠀⠀⠀⠀*data: data describing the disconnection*
+     */
+    pub fn disconnect_now(&mut self, data: u32) {
+        unsafe { enet_peer_disconnect_now(self.as_raw(), data) }
+        self.take_data();
+    }
+
+    /**
+     * Requests a disconnection from the peer once all queued outgoing packets
+     * are sent.
+     * ### Parameters
+     * ⠀⠀⠀⠀⠀*data: data describing the disconnection*
+     */
+    pub fn disconnect_later(&mut self, data: u32) {
+        unsafe { enet_peer_disconnect_later(self.as_raw(), data) }
+    }
+
+    /**
+     * Forcefully disconnects the peer without notifying the foreign host, and
+     * drops the user data.
+     */
+    pub fn reset(&mut self) {
+        unsafe { enet_peer_reset(self.as_raw()) }
+        self.take_data();
+    }
+}
+
+/**
+ * An iterator over the peers of a [`Host`] matching a state filter.
+ *
+ * Created by [`Host::peers`] and [`Host::connected_peers`].
+ *
+ * [`Host`]: crate::host::Host
+ * [`Host::peers`]: crate::host::Host::peers
+ * [`Host::connected_peers`]: crate::host::Host::connected_peers
+ */
+#[derive(Debug)]
+pub struct Peers<'h, T = ()> {
+    host: NonNull<ENetHost>,
+    indices: Range<usize>,
+    filter: fn(PeerState) -> bool,
+    _host: PhantomData<&'h mut Host<T>>,
+}
+
+impl<'h, T> Peers<'h, T> {
+    /**
+     * # Safety
+     * `host` must outlive `'h`.
+     */
+    pub(crate) unsafe fn new(host: NonNull<ENetHost>, filter: fn(PeerState) -> bool) -> Peers<'h, T> {
+        Peers {
+            host,
+            indices: 0..host.as_ref().peerCount,
+            filter,
+            _host: PhantomData,
+        }
+    }
+}
+
+impl<'h, T> Iterator for Peers<'h, T> {
+    type Item = Peer<'h, T>;
+
+    fn next(&mut self) -> Option<Peer<'h, T>> {
+        let host = self.host;
+        let filter = self.filter;
+        self.indices
+            .by_ref()
+            .map(|index| unsafe { Peer::new(host, index) })
+            .find(|peer| filter(peer.state()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.indices.len()))
+    }
+}
+
+/**
+ * Detaches the user data stored in `ENetPeer.data` by [`Peer::set_data`].
+ *
+ * # Safety
+ * `peer` must be valid and its data must be null or a boxed `T`.
+ */
+pub(crate) unsafe fn take_peer_data<T>(peer: *mut ENetPeer) -> Option<T> {
+    let data = ptr::replace(&mut (*peer).data, ptr::null_mut()) as *mut T;
+    if data.is_null() {
+        None
+    } else {
+        Some(*Box::from_raw(data))
+    }
+}
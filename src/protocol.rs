@@ -64,13 +64,14 @@ pub enum ENetProtocolFlag {
     ENET_PROTOCOL_HEADER_SESSION_SHIFT = 12,
 }
 
+#[repr(C, packed)]
 #[derive(Debug, Copy, Clone)]
 pub struct ENetProtocolHeader {
     pub peerID: enet_uint16,
     pub sentTime: enet_uint16,
 }
 
-#[repr(C)]
+#[repr(C, packed)]
 #[derive(Debug, Copy, Clone)]
 pub struct ENetProtocolCommandHeader {
     pub command: enet_uint8,
@@ -78,7 +79,7 @@ pub struct ENetProtocolCommandHeader {
     pub reliableSequenceNumber: enet_uint16,
 }
 
-#[repr(C)]
+#[repr(C, packed)]
 #[derive(Debug, Copy, Clone)]
 pub struct ENetProtocolAcknowledge {
     pub header: ENetProtocolCommandHeader,
@@ -86,7 +87,7 @@ pub struct ENetProtocolAcknowledge {
     pub receivedSentTime: enet_uint16,
 }
 
-#[repr(C)]
+#[repr(C, packed)]
 #[derive(Debug, Copy, Clone)]
 pub struct ENetProtocolConnect {
     pub header: ENetProtocolCommandHeader,
@@ -105,7 +106,7 @@ pub struct ENetProtocolConnect {
     pub data: enet_uint32,
 }
 
-#[repr(C)]
+#[repr(C, packed)]
 #[derive(Debug, Copy, Clone)]
 pub struct ENetProtocolVerifyConnect {
     pub header: ENetProtocolCommandHeader,
@@ -123,7 +124,7 @@ pub struct ENetProtocolVerifyConnect {
     pub connectID: enet_uint32,
 }
 
-#[repr(C)]
+#[repr(C, packed)]
 #[derive(Debug, Copy, Clone)]
 pub struct ENetProtocolBandwidthLimit {
     pub header: ENetProtocolCommandHeader,
@@ -131,7 +132,7 @@ pub struct ENetProtocolBandwidthLimit {
     pub outgoingBandwidth: enet_uint32,
 }
 
-#[repr(C)]
+#[repr(C, packed)]
 #[derive(Debug, Copy, Clone)]
 pub struct ENetProtocolThrottleConfigure {
     pub header: ENetProtocolCommandHeader,
@@ -140,27 +141,27 @@ pub struct ENetProtocolThrottleConfigure {
     pub packetThrottleDeceleration: enet_uint32,
 }
 
-#[repr(C)]
+#[repr(C, packed)]
 #[derive(Debug, Copy, Clone)]
 pub struct ENetProtocolDisconnect {
     pub header: ENetProtocolCommandHeader,
     pub data: enet_uint32,
 }
 
-#[repr(C)]
+#[repr(C, packed)]
 #[derive(Debug, Copy, Clone)]
 pub struct ENetProtocolPing {
     pub header: ENetProtocolCommandHeader,
 }
 
-#[repr(C)]
+#[repr(C, packed)]
 #[derive(Debug, Copy, Clone)]
 pub struct ENetProtocolSendReliable {
     pub header: ENetProtocolCommandHeader,
     pub dataLength: enet_uint16,
 }
 
-#[repr(C)]
+#[repr(C, packed)]
 #[derive(Debug, Copy, Clone)]
 pub struct ENetProtocolSendUnreliable {
     pub header: ENetProtocolCommandHeader,
@@ -168,7 +169,7 @@ pub struct ENetProtocolSendUnreliable {
     pub dataLength: enet_uint16,
 }
 
-#[repr(C)]
+#[repr(C, packed)]
 #[derive(Debug, Copy, Clone)]
 pub struct ENetProtocolSendUnsequenced {
     pub header: ENetProtocolCommandHeader,
@@ -176,7 +177,7 @@ pub struct ENetProtocolSendUnsequenced {
     pub dataLength: enet_uint16,
 }
 
-#[repr(C)]
+#[repr(C, packed)]
 #[derive(Debug, Copy, Clone)]
 pub struct ENetProtocolSendFragment {
     pub header: ENetProtocolCommandHeader,
@@ -188,7 +189,7 @@ pub struct ENetProtocolSendFragment {
     pub fragmentOffset: enet_uint32,
 }
 
-#[repr(C)]
+#[repr(C, packed)]
 #[derive(Copy, Clone)]
 pub union ENetProtocol {
     pub header: ENetProtocolCommandHeader,
@@ -0,0 +1,182 @@
+/*
+ * query.rs
+ *
+ * Server query protocol answered on the game port
+ */
+
+use std::{
+    collections::hash_map::RandomState,
+    convert::TryInto,
+    hash::BuildHasher,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    enet::{ENetAddress, ENET_HOST_DEFAULT_MTU},
+    error::Error,
+    intercept::{InterceptAction, InterceptContext},
+};
+
+/** prefix of a query datagram, which the ENet protocol never produces */
+pub const QUERY_REQUEST: &[u8] = b"\xFF\xFF\xFF\xFFENetQuery\0";
+
+/** prefix of a challenge sent in reply to a query lacking a valid one */
+pub const QUERY_CHALLENGE: &[u8] = b"\xFF\xFF\xFF\xFFA";
+
+/** prefix of a query response */
+pub const QUERY_RESPONSE: &[u8] = b"\xFF\xFF\xFF\xFFI";
+
+/** largest response, so that it fits a single datagram */
+pub const QUERY_RESPONSE_MAX: usize = ENET_HOST_DEFAULT_MTU as usize;
+
+/** size of a response without the name and info blob */
+const RESPONSE_OVERHEAD: usize = 5 + 1 + 4 + 4 + 2;
+
+/** how long a challenge is valid, give or take as much again */
+const CHALLENGE_PERIOD: Duration = Duration::from_secs(30);
+
+/**
+ * Answers server browser queries on the host's own socket, so no second port
+ * is needed. Install it with [`Host::set_query_responder`], or call
+ * [`respond`] from a hook of your own to combine it with other intercepts.
+ *
+ * A query is a datagram starting with [`QUERY_REQUEST`], followed by a
+ * challenge as a little endian u32. A query without a valid challenge is
+ * answered with [`QUERY_CHALLENGE`] followed by one, which is smaller than
+ * the query, so a spoofed sender address cannot turn the server into an
+ * amplifier; the browser then repeats the query with the challenge. Anything
+ * after the challenge is ignored. The response, sent back to the querying
+ * address, is laid out as follows, integers in little endian:
+ *
+ * | field       | type                           |
+ * |-------------|--------------------------------|
+ * | header      | [`QUERY_RESPONSE`]             |
+ * | name        | UTF-8, NUL terminated          |
+ * | connected   | u32, `ENetHost.connectedPeers` |
+ * | peer limit  | u32, `ENetHost.peerCount`      |
+ * | info length | u16                            |
+ * | info        | the user-supplied info blob    |
+ *
+ * Responses are at most [`QUERY_RESPONSE_MAX`] bytes.
+ *
+ * [`Host::set_query_responder`]: crate::host::Host::set_query_responder
+ * [`respond`]: QueryResponder::respond
+ */
+#[derive(Debug, Clone)]
+pub struct QueryResponder {
+    name: String,
+    info: Vec<u8>,
+    /** keys the challenges, so they cannot be guessed */
+    secret: RandomState,
+    created: Instant,
+}
+
+impl QueryResponder {
+    /**
+     * Creates a responder, failing if the response would not fit a datagram.
+     * ### Parameters
+     * ⠀⠀⠀⠀⠀*name: server name reported to browsers, NUL bytes are stripped \
+     * ⠀⠀⠀⠀⠀info: opaque info blob*
+     */
+    pub fn new(name: &str, info: &[u8]) -> Result<QueryResponder, Error> {
+        let mut responder = QueryResponder {
+            name: String::new(),
+            info: Vec::new(),
+            secret: RandomState::new(),
+            created: Instant::now(),
+        };
+        responder.set_name(name)?;
+        responder.set_info(info)?;
+        Ok(responder)
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /** sets the server name, failing if the response would not fit a datagram */
+    pub fn set_name(&mut self, name: &str) -> Result<(), Error> {
+        let name = name.replace('\0', "");
+        check_size(name.len() + self.info.len())?;
+        self.name = name;
+        Ok(())
+    }
+
+    pub fn info(&self) -> &[u8] {
+        &self.info
+    }
+
+    /** sets the info blob, failing if the response would not fit a datagram */
+    pub fn set_info(&mut self, info: &[u8]) -> Result<(), Error> {
+        check_size(self.name.len() + info.len())?;
+        self.info = info.to_vec();
+        Ok(())
+    }
+
+    /**
+     * Answers the intercepted datagram if it is a query, returning
+     * [`InterceptAction::Intercept`] so it never reaches the ENet protocol,
+     * and [`InterceptAction::Ignore`] otherwise.
+     *
+     * Replies are best effort, like any datagram: anyone can send a query,
+     * e.g. spoofed from an address that cannot be sent to, so a reply that
+     * fails is dropped rather than failing the host.
+     */
+    pub fn respond(&self, context: &InterceptContext<'_>) -> InterceptAction {
+        let data = context.data();
+        if !data.starts_with(QUERY_REQUEST) {
+            return InterceptAction::Ignore;
+        }
+
+        let sender = context.sender();
+        let challenge = data[QUERY_REQUEST.len()..]
+            .get(..4)
+            .map(|challenge| u32::from_le_bytes(challenge.try_into().unwrap()));
+        let epoch = self.epoch();
+        let valid = challenge.is_some_and(|challenge| {
+            challenge == self.challenge(&sender, epoch) || challenge == self.challenge(&sender, epoch.wrapping_sub(1))
+        });
+
+        let reply = if valid {
+            self.response(context.connected_count(), context.peer_count())
+        } else {
+            let mut reply = QUERY_CHALLENGE.to_vec();
+            reply.extend_from_slice(&self.challenge(&sender, epoch).to_le_bytes());
+            reply
+        };
+        let _ = context.reply(&reply);
+
+        InterceptAction::Intercept
+    }
+
+    /** challenge period since the responder was created */
+    fn epoch(&self) -> u64 {
+        self.created.elapsed().as_secs() / CHALLENGE_PERIOD.as_secs()
+    }
+
+    /** challenge of `sender` in `epoch` */
+    fn challenge(&self, sender: &ENetAddress, epoch: u64) -> u32 {
+        self.secret.hash_one((sender.host, sender.port, epoch)) as u32
+    }
+
+    fn response(&self, connected: usize, peer_count: usize) -> Vec<u8> {
+        let mut response = Vec::with_capacity(RESPONSE_OVERHEAD + self.name.len() + self.info.len());
+        response.extend_from_slice(QUERY_RESPONSE);
+        response.extend_from_slice(self.name.as_bytes());
+        response.push(0);
+        response.extend_from_slice(&(connected as u32).to_le_bytes());
+        response.extend_from_slice(&(peer_count as u32).to_le_bytes());
+        response.extend_from_slice(&(self.info.len() as u16).to_le_bytes());
+        response.extend_from_slice(&self.info);
+        response
+    }
+}
+
+fn check_size(contents: usize) -> Result<(), Error> {
+    let size = RESPONSE_OVERHEAD + contents;
+    if size > QUERY_RESPONSE_MAX {
+        return Err(Error::QueryTooLarge { size, limit: QUERY_RESPONSE_MAX });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,117 @@
+/*
+ * range_coder.rs
+ *
+ * Safe ENet range coder
+ */
+
+use std::ptr::NonNull;
+
+use libc::c_void;
+
+use crate::{
+    compressor::Compressor,
+    context::Enet,
+    enet::{
+        enet_range_coder_create,
+        enet_range_coder_destroy,
+        enet_range_coder_compress,
+        enet_range_coder_decompress,
+    },
+    error::Error,
+    header::ENetBuffer,
+};
+
+/**
+ * An owned instance of ENet's built-in range coder, the compressor installed
+ * by [`Host::compress_with_range_coder`].
+ *
+ * It can be used on its own, e.g. to measure compression ratios of captured
+ * traffic offline, or installed on a host as a [`Compressor`].
+ *
+ * [`Host::compress_with_range_coder`]: crate::host::Host::compress_with_range_coder
+ * [`Compressor`]: crate::compressor::Compressor
+ */
+#[derive(Debug)]
+pub struct RangeCoder {
+    context: NonNull<c_void>,
+}
+
+unsafe impl Send for RangeCoder {}
+
+impl RangeCoder {
+    /**
+     * Creates a range coder, whose state ENet allocates; `enet` ensures it
+     * comes from the allocator ENet frees it with.
+     */
+    pub fn new(_enet: &Enet) -> Result<RangeCoder, Error> {
+        NonNull::new(unsafe { enet_range_coder_create() })
+            .map(|context| RangeCoder { context })
+            .ok_or(Error::CompressorFailed)
+    }
+
+    /**
+     * Compresses the concatenation of `input`, returning None if the range
+     * coder failed, e.g. because the data is incompressible.
+     */
+    pub fn compress(&mut self, input: &[&[u8]]) -> Option<Vec<u8>> {
+        let length = input.iter().map(|buffer| buffer.len()).sum::<usize>();
+        // leave room for data that does not compress
+        let mut out = vec![0; length + length / 2 + 64];
+        let written = Compressor::compress(self, input, &mut out)?;
+        out.truncate(written);
+        Some(out)
+    }
+
+    /**
+     * Decompresses `input`, producing at most `max` bytes, returning None if
+     * the data is corrupt or decompresses to more than `max` bytes.
+     */
+    pub fn decompress(&mut self, input: &[u8], max: usize) -> Option<Vec<u8>> {
+        let mut out = vec![0; max];
+        let written = Compressor::decompress(self, input, &mut out)?;
+        out.truncate(written);
+        Some(out)
+    }
+}
+
+impl Compressor for RangeCoder {
+    fn compress(&mut self, input: &[&[u8]], out: &mut [u8]) -> Option<usize> {
+        let buffers = input
+            .iter()
+            .map(|buffer| ENetBuffer { dataLength: buffer.len(), data: buffer.as_ptr() as *mut c_void })
+            .collect::<Vec<_>>();
+        let length = input.iter().map(|buffer| buffer.len()).sum();
+        let written = unsafe {
+            enet_range_coder_compress(
+                self.context.as_ptr(),
+                buffers.as_ptr(),
+                buffers.len(),
+                length,
+                out.as_mut_ptr(),
+                out.len(),
+            )
+        };
+
+        if written == 0 { None } else { Some(written) }
+    }
+
+    fn decompress(&mut self, input: &[u8], out: &mut [u8]) -> Option<usize> {
+        let written = unsafe {
+            enet_range_coder_decompress(
+                self.context.as_ptr(),
+                input.as_ptr(),
+                input.len(),
+                out.as_mut_ptr(),
+                out.len(),
+            )
+        };
+
+        if written == 0 { None } else { Some(written) }
+    }
+}
+
+impl Drop for RangeCoder {
+    fn drop(&mut self) {
+        unsafe { enet_range_coder_destroy(self.context.as_ptr()) }
+    }
+}
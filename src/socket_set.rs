@@ -0,0 +1,126 @@
+/*
+ * socket_set.rs
+ *
+ * Safe ENet socket sets
+ */
+
+use std::{fmt, io, mem, ptr};
+
+use crate::{
+    enet::enet_socketset_select,
+    error::Error,
+    header::{
+        ENetSocket,
+        ENetSocketSet,
+        enet_socketset_add,
+        enet_socketset_check,
+        enet_socketset_empty,
+        enet_socketset_remove,
+    },
+};
+
+/**
+ * A set of sockets to wait on with [`SocketSet::select`], e.g. the sockets
+ * of several hosts.
+ *
+ * This is an `fd_set` on Unix and a Winsock `fd_set` on Windows, so it holds
+ * sockets below `FD_SETSIZE` on Unix and at most 64 sockets on Windows.
+ */
+#[derive(Clone)]
+pub struct SocketSet {
+    inner: ENetSocketSet,
+    max: Option<ENetSocket>,
+}
+
+impl SocketSet {
+    pub fn new() -> SocketSet {
+        // an all zero fd_set is an empty one on every platform
+        let mut set = SocketSet { inner: unsafe { mem::zeroed() }, max: None };
+        set.clear();
+        set
+    }
+
+    /**
+     * Adds `socket` to the set, failing if the set cannot hold it.
+     */
+    pub fn add(&mut self, socket: ENetSocket) -> Result<(), Error> {
+        if !in_range(socket) {
+            return Err(Error::Socket(io::ErrorKind::InvalidInput.into()));
+        }
+
+        unsafe { enet_socketset_add(&mut self.inner, socket) };
+        // a full Winsock set ignores the socket
+        if !self.contains(socket) {
+            return Err(Error::Socket(io::ErrorKind::InvalidInput.into()));
+        }
+
+        self.max = Some(self.max.map_or(socket, |max| max.max(socket)));
+        Ok(())
+    }
+
+    pub fn remove(&mut self, socket: ENetSocket) {
+        if in_range(socket) {
+            unsafe { enet_socketset_remove(&mut self.inner, socket) }
+        }
+    }
+
+    pub fn contains(&self, socket: ENetSocket) -> bool {
+        in_range(socket) && unsafe { enet_socketset_check(&self.inner, socket) }
+    }
+
+    pub fn clear(&mut self) {
+        enet_socketset_empty(&mut self.inner);
+        self.max = None;
+    }
+
+    pub fn as_raw(&mut self) -> *mut ENetSocketSet {
+        &mut self.inner
+    }
+
+    /**
+     * Waits until a socket of `read` can be read from or a socket of `write`
+     * can be written to, leaving only those sockets in the sets. Returns the
+     * number of ready sockets, 0 if the timeout expired.
+     * ### Parameters
+     * ⠀⠀⠀⠀⠀*read: sockets to wait on for reading \
+     * ⠀⠀⠀⠀⠀write: sockets to wait on for writing \
+     * ⠀⠀⠀⠀⠀timeout: number of milliseconds to wait*
+     */
+    pub fn select(
+        mut read: Option<&mut SocketSet>,
+        mut write: Option<&mut SocketSet>,
+        timeout: u32,
+    ) -> Result<usize, Error> {
+        let max = read.iter().chain(write.iter()).filter_map(|set| set.max).max().unwrap_or(0);
+        let read_set = read.as_mut().map_or(ptr::null_mut(), |set| set.as_raw());
+        let write_set = write.as_mut().map_or(ptr::null_mut(), |set| set.as_raw());
+
+        match unsafe { enet_socketset_select(max, read_set, write_set, timeout) } {
+            r if r < 0 => Err(Error::Socket(io::Error::last_os_error())),
+            r => Ok(r as usize),
+        }
+    }
+}
+
+/** whether an `fd_set` bitmap has room for the descriptor */
+#[cfg(unix)]
+fn in_range(socket: ENetSocket) -> bool {
+    socket >= 0 && (socket as usize) < libc::FD_SETSIZE
+}
+
+#[cfg(windows)]
+fn in_range(_socket: ENetSocket) -> bool {
+    true
+}
+
+impl Default for SocketSet {
+    fn default() -> SocketSet {
+        SocketSet::new()
+    }
+}
+
+impl fmt::Debug for SocketSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SocketSet").field("max", &self.max).finish_non_exhaustive()
+    }
+}
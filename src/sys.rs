@@ -0,0 +1,20 @@
+/*
+ * sys.rs
+ *
+ * Raw ENet bindings generated by bindgen from enet/enet.h
+ *
+ * With the `bindgen` feature they are generated at build time, which needs
+ * libclang; otherwise the pregenerated src/sys/bindings.rs is used, which
+ * tests/bindings.rs keeps in sync. ENetSocket, ENetSocketSet and ENetBuffer
+ * depend on the platform and come from header.rs.
+ */
+
+#![allow(non_camel_case_types, non_upper_case_globals, non_snake_case, clippy::all)]
+// the same functions are declared in enet.rs with the hand-written types
+#![allow(clashing_extern_declarations)]
+
+#[cfg(feature = "bindgen")]
+include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+
+#[cfg(not(feature = "bindgen"))]
+include!("sys/bindings.rs");
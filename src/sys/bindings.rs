@@ -0,0 +1,868 @@
+/* automatically generated by rust-bindgen 0.72.1 */
+
+use crate::header::{ENetBuffer, ENetSocket, ENetSocketSet};
+
+pub type enet_uint8 = ::std::os::raw::c_uchar;
+pub type enet_uint16 = ::std::os::raw::c_ushort;
+pub type enet_uint32 = ::std::os::raw::c_uint;
+pub const ENET_PROTOCOL_MINIMUM_MTU: _bindgen_ty_1 = 576;
+pub const ENET_PROTOCOL_MAXIMUM_MTU: _bindgen_ty_1 = 4096;
+pub const ENET_PROTOCOL_MAXIMUM_PACKET_COMMANDS: _bindgen_ty_1 = 32;
+pub const ENET_PROTOCOL_MINIMUM_WINDOW_SIZE: _bindgen_ty_1 = 4096;
+pub const ENET_PROTOCOL_MAXIMUM_WINDOW_SIZE: _bindgen_ty_1 = 65536;
+pub const ENET_PROTOCOL_MINIMUM_CHANNEL_COUNT: _bindgen_ty_1 = 1;
+pub const ENET_PROTOCOL_MAXIMUM_CHANNEL_COUNT: _bindgen_ty_1 = 255;
+pub const ENET_PROTOCOL_MAXIMUM_PEER_ID: _bindgen_ty_1 = 4095;
+pub const ENET_PROTOCOL_MAXIMUM_FRAGMENT_COUNT: _bindgen_ty_1 = 1048576;
+pub type _bindgen_ty_1 = ::std::os::raw::c_uint;
+pub const ENET_PROTOCOL_COMMAND_NONE: _ENetProtocolCommand = 0;
+pub const ENET_PROTOCOL_COMMAND_ACKNOWLEDGE: _ENetProtocolCommand = 1;
+pub const ENET_PROTOCOL_COMMAND_CONNECT: _ENetProtocolCommand = 2;
+pub const ENET_PROTOCOL_COMMAND_VERIFY_CONNECT: _ENetProtocolCommand = 3;
+pub const ENET_PROTOCOL_COMMAND_DISCONNECT: _ENetProtocolCommand = 4;
+pub const ENET_PROTOCOL_COMMAND_PING: _ENetProtocolCommand = 5;
+pub const ENET_PROTOCOL_COMMAND_SEND_RELIABLE: _ENetProtocolCommand = 6;
+pub const ENET_PROTOCOL_COMMAND_SEND_UNRELIABLE: _ENetProtocolCommand = 7;
+pub const ENET_PROTOCOL_COMMAND_SEND_FRAGMENT: _ENetProtocolCommand = 8;
+pub const ENET_PROTOCOL_COMMAND_SEND_UNSEQUENCED: _ENetProtocolCommand = 9;
+pub const ENET_PROTOCOL_COMMAND_BANDWIDTH_LIMIT: _ENetProtocolCommand = 10;
+pub const ENET_PROTOCOL_COMMAND_THROTTLE_CONFIGURE: _ENetProtocolCommand = 11;
+pub const ENET_PROTOCOL_COMMAND_SEND_UNRELIABLE_FRAGMENT: _ENetProtocolCommand = 12;
+pub const ENET_PROTOCOL_COMMAND_COUNT: _ENetProtocolCommand = 13;
+pub const ENET_PROTOCOL_COMMAND_MASK: _ENetProtocolCommand = 15;
+pub type _ENetProtocolCommand = ::std::os::raw::c_uint;
+pub use self::_ENetProtocolCommand as ENetProtocolCommand;
+pub const ENET_PROTOCOL_COMMAND_FLAG_ACKNOWLEDGE: _ENetProtocolFlag = 128;
+pub const ENET_PROTOCOL_COMMAND_FLAG_UNSEQUENCED: _ENetProtocolFlag = 64;
+pub const ENET_PROTOCOL_HEADER_FLAG_COMPRESSED: _ENetProtocolFlag = 16384;
+pub const ENET_PROTOCOL_HEADER_FLAG_SENT_TIME: _ENetProtocolFlag = 32768;
+pub const ENET_PROTOCOL_HEADER_FLAG_MASK: _ENetProtocolFlag = 49152;
+pub const ENET_PROTOCOL_HEADER_SESSION_MASK: _ENetProtocolFlag = 12288;
+pub const ENET_PROTOCOL_HEADER_SESSION_SHIFT: _ENetProtocolFlag = 12;
+pub type _ENetProtocolFlag = ::std::os::raw::c_uint;
+pub use self::_ENetProtocolFlag as ENetProtocolFlag;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ENetProtocolHeader {
+    pub peerID: enet_uint16,
+    pub sentTime: enet_uint16,
+}
+pub type ENetProtocolHeader = _ENetProtocolHeader;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ENetProtocolCommandHeader {
+    pub command: enet_uint8,
+    pub channelID: enet_uint8,
+    pub reliableSequenceNumber: enet_uint16,
+}
+pub type ENetProtocolCommandHeader = _ENetProtocolCommandHeader;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ENetProtocolAcknowledge {
+    pub header: ENetProtocolCommandHeader,
+    pub receivedReliableSequenceNumber: enet_uint16,
+    pub receivedSentTime: enet_uint16,
+}
+pub type ENetProtocolAcknowledge = _ENetProtocolAcknowledge;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ENetProtocolConnect {
+    pub header: ENetProtocolCommandHeader,
+    pub outgoingPeerID: enet_uint16,
+    pub incomingSessionID: enet_uint8,
+    pub outgoingSessionID: enet_uint8,
+    pub mtu: enet_uint32,
+    pub windowSize: enet_uint32,
+    pub channelCount: enet_uint32,
+    pub incomingBandwidth: enet_uint32,
+    pub outgoingBandwidth: enet_uint32,
+    pub packetThrottleInterval: enet_uint32,
+    pub packetThrottleAcceleration: enet_uint32,
+    pub packetThrottleDeceleration: enet_uint32,
+    pub connectID: enet_uint32,
+    pub data: enet_uint32,
+}
+pub type ENetProtocolConnect = _ENetProtocolConnect;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ENetProtocolVerifyConnect {
+    pub header: ENetProtocolCommandHeader,
+    pub outgoingPeerID: enet_uint16,
+    pub incomingSessionID: enet_uint8,
+    pub outgoingSessionID: enet_uint8,
+    pub mtu: enet_uint32,
+    pub windowSize: enet_uint32,
+    pub channelCount: enet_uint32,
+    pub incomingBandwidth: enet_uint32,
+    pub outgoingBandwidth: enet_uint32,
+    pub packetThrottleInterval: enet_uint32,
+    pub packetThrottleAcceleration: enet_uint32,
+    pub packetThrottleDeceleration: enet_uint32,
+    pub connectID: enet_uint32,
+}
+pub type ENetProtocolVerifyConnect = _ENetProtocolVerifyConnect;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ENetProtocolBandwidthLimit {
+    pub header: ENetProtocolCommandHeader,
+    pub incomingBandwidth: enet_uint32,
+    pub outgoingBandwidth: enet_uint32,
+}
+pub type ENetProtocolBandwidthLimit = _ENetProtocolBandwidthLimit;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ENetProtocolThrottleConfigure {
+    pub header: ENetProtocolCommandHeader,
+    pub packetThrottleInterval: enet_uint32,
+    pub packetThrottleAcceleration: enet_uint32,
+    pub packetThrottleDeceleration: enet_uint32,
+}
+pub type ENetProtocolThrottleConfigure = _ENetProtocolThrottleConfigure;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ENetProtocolDisconnect {
+    pub header: ENetProtocolCommandHeader,
+    pub data: enet_uint32,
+}
+pub type ENetProtocolDisconnect = _ENetProtocolDisconnect;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ENetProtocolPing {
+    pub header: ENetProtocolCommandHeader,
+}
+pub type ENetProtocolPing = _ENetProtocolPing;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ENetProtocolSendReliable {
+    pub header: ENetProtocolCommandHeader,
+    pub dataLength: enet_uint16,
+}
+pub type ENetProtocolSendReliable = _ENetProtocolSendReliable;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ENetProtocolSendUnreliable {
+    pub header: ENetProtocolCommandHeader,
+    pub unreliableSequenceNumber: enet_uint16,
+    pub dataLength: enet_uint16,
+}
+pub type ENetProtocolSendUnreliable = _ENetProtocolSendUnreliable;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ENetProtocolSendUnsequenced {
+    pub header: ENetProtocolCommandHeader,
+    pub unsequencedGroup: enet_uint16,
+    pub dataLength: enet_uint16,
+}
+pub type ENetProtocolSendUnsequenced = _ENetProtocolSendUnsequenced;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ENetProtocolSendFragment {
+    pub header: ENetProtocolCommandHeader,
+    pub startSequenceNumber: enet_uint16,
+    pub dataLength: enet_uint16,
+    pub fragmentCount: enet_uint32,
+    pub fragmentNumber: enet_uint32,
+    pub totalLength: enet_uint32,
+    pub fragmentOffset: enet_uint32,
+}
+pub type ENetProtocolSendFragment = _ENetProtocolSendFragment;
+#[repr(C, packed)]
+#[derive(Copy, Clone)]
+pub union _ENetProtocol {
+    pub header: ENetProtocolCommandHeader,
+    pub acknowledge: ENetProtocolAcknowledge,
+    pub connect: ENetProtocolConnect,
+    pub verifyConnect: ENetProtocolVerifyConnect,
+    pub disconnect: ENetProtocolDisconnect,
+    pub ping: ENetProtocolPing,
+    pub sendReliable: ENetProtocolSendReliable,
+    pub sendUnreliable: ENetProtocolSendUnreliable,
+    pub sendUnsequenced: ENetProtocolSendUnsequenced,
+    pub sendFragment: ENetProtocolSendFragment,
+    pub bandwidthLimit: ENetProtocolBandwidthLimit,
+    pub throttleConfigure: ENetProtocolThrottleConfigure,
+}
+pub type ENetProtocol = _ENetProtocol;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ENetListNode {
+    pub next: *mut _ENetListNode,
+    pub previous: *mut _ENetListNode,
+}
+pub type ENetListNode = _ENetListNode;
+pub type ENetListIterator = *mut ENetListNode;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ENetList {
+    pub sentinel: ENetListNode,
+}
+pub type ENetList = _ENetList;
+unsafe extern "C" {
+    pub fn enet_list_clear(arg1: *mut ENetList);
+}
+unsafe extern "C" {
+    pub fn enet_list_insert(
+        arg1: ENetListIterator,
+        arg2: *mut ::std::os::raw::c_void,
+    ) -> ENetListIterator;
+}
+unsafe extern "C" {
+    pub fn enet_list_remove(arg1: ENetListIterator) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    pub fn enet_list_move(
+        arg1: ENetListIterator,
+        arg2: *mut ::std::os::raw::c_void,
+        arg3: *mut ::std::os::raw::c_void,
+    ) -> ENetListIterator;
+}
+unsafe extern "C" {
+    pub fn enet_list_size(arg1: *mut ENetList) -> usize;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ENetCallbacks {
+    pub malloc:
+        ::std::option::Option<unsafe extern "C" fn(size: usize) -> *mut ::std::os::raw::c_void>,
+    pub free: ::std::option::Option<unsafe extern "C" fn(memory: *mut ::std::os::raw::c_void)>,
+    pub no_memory: ::std::option::Option<unsafe extern "C" fn()>,
+}
+pub type ENetCallbacks = _ENetCallbacks;
+unsafe extern "C" {
+    pub fn enet_malloc(arg1: usize) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    pub fn enet_free(arg1: *mut ::std::os::raw::c_void);
+}
+pub const ENET_VERSION_MAJOR: u32 = 1;
+pub const ENET_VERSION_MINOR: u32 = 3;
+pub const ENET_VERSION_PATCH: u32 = 17;
+pub type ENetVersion = enet_uint32;
+pub const ENET_SOCKET_TYPE_STREAM: _ENetSocketType = 1;
+pub const ENET_SOCKET_TYPE_DATAGRAM: _ENetSocketType = 2;
+pub type _ENetSocketType = ::std::os::raw::c_uint;
+pub use self::_ENetSocketType as ENetSocketType;
+pub const ENET_SOCKET_WAIT_NONE: _ENetSocketWait = 0;
+pub const ENET_SOCKET_WAIT_SEND: _ENetSocketWait = 1;
+pub const ENET_SOCKET_WAIT_RECEIVE: _ENetSocketWait = 2;
+pub const ENET_SOCKET_WAIT_INTERRUPT: _ENetSocketWait = 4;
+pub type _ENetSocketWait = ::std::os::raw::c_uint;
+pub use self::_ENetSocketWait as ENetSocketWait;
+pub const ENET_SOCKOPT_NONBLOCK: _ENetSocketOption = 1;
+pub const ENET_SOCKOPT_BROADCAST: _ENetSocketOption = 2;
+pub const ENET_SOCKOPT_RCVBUF: _ENetSocketOption = 3;
+pub const ENET_SOCKOPT_SNDBUF: _ENetSocketOption = 4;
+pub const ENET_SOCKOPT_REUSEADDR: _ENetSocketOption = 5;
+pub const ENET_SOCKOPT_RCVTIMEO: _ENetSocketOption = 6;
+pub const ENET_SOCKOPT_SNDTIMEO: _ENetSocketOption = 7;
+pub const ENET_SOCKOPT_ERROR: _ENetSocketOption = 8;
+pub const ENET_SOCKOPT_NODELAY: _ENetSocketOption = 9;
+pub type _ENetSocketOption = ::std::os::raw::c_uint;
+pub use self::_ENetSocketOption as ENetSocketOption;
+pub const ENET_SOCKET_SHUTDOWN_READ: _ENetSocketShutdown = 0;
+pub const ENET_SOCKET_SHUTDOWN_WRITE: _ENetSocketShutdown = 1;
+pub const ENET_SOCKET_SHUTDOWN_READ_WRITE: _ENetSocketShutdown = 2;
+pub type _ENetSocketShutdown = ::std::os::raw::c_uint;
+pub use self::_ENetSocketShutdown as ENetSocketShutdown;
+pub const ENET_HOST_ANY: u32 = 0;
+pub const ENET_HOST_BROADCAST: u32 = 4294967295;
+pub const ENET_PORT_ANY: u32 = 0;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ENetAddress {
+    pub host: enet_uint32,
+    pub port: enet_uint16,
+}
+pub type ENetAddress = _ENetAddress;
+pub const ENET_PACKET_FLAG_RELIABLE: _ENetPacketFlag = 1;
+pub const ENET_PACKET_FLAG_UNSEQUENCED: _ENetPacketFlag = 2;
+pub const ENET_PACKET_FLAG_NO_ALLOCATE: _ENetPacketFlag = 4;
+pub const ENET_PACKET_FLAG_UNRELIABLE_FRAGMENT: _ENetPacketFlag = 8;
+pub const ENET_PACKET_FLAG_SENT: _ENetPacketFlag = 256;
+pub type _ENetPacketFlag = ::std::os::raw::c_uint;
+pub use self::_ENetPacketFlag as ENetPacketFlag;
+pub type ENetPacketFreeCallback =
+    ::std::option::Option<unsafe extern "C" fn(arg1: *mut _ENetPacket)>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ENetPacket {
+    pub referenceCount: usize,
+    pub flags: enet_uint32,
+    pub data: *mut enet_uint8,
+    pub dataLength: usize,
+    pub freeCallback: ENetPacketFreeCallback,
+    pub userData: *mut ::std::os::raw::c_void,
+}
+pub type ENetPacket = _ENetPacket;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _ENetAcknowledgement {
+    pub acknowledgementList: ENetListNode,
+    pub sentTime: enet_uint32,
+    pub command: ENetProtocol,
+}
+pub type ENetAcknowledgement = _ENetAcknowledgement;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _ENetOutgoingCommand {
+    pub outgoingCommandList: ENetListNode,
+    pub reliableSequenceNumber: enet_uint16,
+    pub unreliableSequenceNumber: enet_uint16,
+    pub sentTime: enet_uint32,
+    pub roundTripTimeout: enet_uint32,
+    pub roundTripTimeoutLimit: enet_uint32,
+    pub fragmentOffset: enet_uint32,
+    pub fragmentLength: enet_uint16,
+    pub sendAttempts: enet_uint16,
+    pub command: ENetProtocol,
+    pub packet: *mut ENetPacket,
+}
+pub type ENetOutgoingCommand = _ENetOutgoingCommand;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _ENetIncomingCommand {
+    pub incomingCommandList: ENetListNode,
+    pub reliableSequenceNumber: enet_uint16,
+    pub unreliableSequenceNumber: enet_uint16,
+    pub command: ENetProtocol,
+    pub fragmentCount: enet_uint32,
+    pub fragmentsRemaining: enet_uint32,
+    pub fragments: *mut enet_uint32,
+    pub packet: *mut ENetPacket,
+}
+pub type ENetIncomingCommand = _ENetIncomingCommand;
+pub const ENET_PEER_STATE_DISCONNECTED: _ENetPeerState = 0;
+pub const ENET_PEER_STATE_CONNECTING: _ENetPeerState = 1;
+pub const ENET_PEER_STATE_ACKNOWLEDGING_CONNECT: _ENetPeerState = 2;
+pub const ENET_PEER_STATE_CONNECTION_PENDING: _ENetPeerState = 3;
+pub const ENET_PEER_STATE_CONNECTION_SUCCEEDED: _ENetPeerState = 4;
+pub const ENET_PEER_STATE_CONNECTED: _ENetPeerState = 5;
+pub const ENET_PEER_STATE_DISCONNECT_LATER: _ENetPeerState = 6;
+pub const ENET_PEER_STATE_DISCONNECTING: _ENetPeerState = 7;
+pub const ENET_PEER_STATE_ACKNOWLEDGING_DISCONNECT: _ENetPeerState = 8;
+pub const ENET_PEER_STATE_ZOMBIE: _ENetPeerState = 9;
+pub type _ENetPeerState = ::std::os::raw::c_uint;
+pub use self::_ENetPeerState as ENetPeerState;
+pub const ENET_HOST_RECEIVE_BUFFER_SIZE: _bindgen_ty_2 = 262144;
+pub const ENET_HOST_SEND_BUFFER_SIZE: _bindgen_ty_2 = 262144;
+pub const ENET_HOST_BANDWIDTH_THROTTLE_INTERVAL: _bindgen_ty_2 = 1000;
+pub const ENET_HOST_DEFAULT_MTU: _bindgen_ty_2 = 1400;
+pub const ENET_HOST_DEFAULT_MAXIMUM_PACKET_SIZE: _bindgen_ty_2 = 33554432;
+pub const ENET_HOST_DEFAULT_MAXIMUM_WAITING_DATA: _bindgen_ty_2 = 33554432;
+pub const ENET_PEER_DEFAULT_ROUND_TRIP_TIME: _bindgen_ty_2 = 500;
+pub const ENET_PEER_DEFAULT_PACKET_THROTTLE: _bindgen_ty_2 = 32;
+pub const ENET_PEER_PACKET_THROTTLE_SCALE: _bindgen_ty_2 = 32;
+pub const ENET_PEER_PACKET_THROTTLE_COUNTER: _bindgen_ty_2 = 7;
+pub const ENET_PEER_PACKET_THROTTLE_ACCELERATION: _bindgen_ty_2 = 2;
+pub const ENET_PEER_PACKET_THROTTLE_DECELERATION: _bindgen_ty_2 = 2;
+pub const ENET_PEER_PACKET_THROTTLE_INTERVAL: _bindgen_ty_2 = 5000;
+pub const ENET_PEER_PACKET_LOSS_SCALE: _bindgen_ty_2 = 65536;
+pub const ENET_PEER_PACKET_LOSS_INTERVAL: _bindgen_ty_2 = 10000;
+pub const ENET_PEER_WINDOW_SIZE_SCALE: _bindgen_ty_2 = 65536;
+pub const ENET_PEER_TIMEOUT_LIMIT: _bindgen_ty_2 = 32;
+pub const ENET_PEER_TIMEOUT_MINIMUM: _bindgen_ty_2 = 5000;
+pub const ENET_PEER_TIMEOUT_MAXIMUM: _bindgen_ty_2 = 30000;
+pub const ENET_PEER_PING_INTERVAL: _bindgen_ty_2 = 500;
+pub const ENET_PEER_UNSEQUENCED_WINDOWS: _bindgen_ty_2 = 64;
+pub const ENET_PEER_UNSEQUENCED_WINDOW_SIZE: _bindgen_ty_2 = 1024;
+pub const ENET_PEER_FREE_UNSEQUENCED_WINDOWS: _bindgen_ty_2 = 32;
+pub const ENET_PEER_RELIABLE_WINDOWS: _bindgen_ty_2 = 16;
+pub const ENET_PEER_RELIABLE_WINDOW_SIZE: _bindgen_ty_2 = 4096;
+pub const ENET_PEER_FREE_RELIABLE_WINDOWS: _bindgen_ty_2 = 8;
+pub type _bindgen_ty_2 = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ENetChannel {
+    pub outgoingReliableSequenceNumber: enet_uint16,
+    pub outgoingUnreliableSequenceNumber: enet_uint16,
+    pub usedReliableWindows: enet_uint16,
+    pub reliableWindows: [enet_uint16; 16usize],
+    pub incomingReliableSequenceNumber: enet_uint16,
+    pub incomingUnreliableSequenceNumber: enet_uint16,
+    pub incomingReliableCommands: ENetList,
+    pub incomingUnreliableCommands: ENetList,
+}
+pub type ENetChannel = _ENetChannel;
+pub const ENET_PEER_FLAG_NEEDS_DISPATCH: _ENetPeerFlag = 1;
+pub type _ENetPeerFlag = ::std::os::raw::c_uint;
+pub use self::_ENetPeerFlag as ENetPeerFlag;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ENetPeer {
+    pub dispatchList: ENetListNode,
+    pub host: *mut _ENetHost,
+    pub outgoingPeerID: enet_uint16,
+    pub incomingPeerID: enet_uint16,
+    pub connectID: enet_uint32,
+    pub outgoingSessionID: enet_uint8,
+    pub incomingSessionID: enet_uint8,
+    pub address: ENetAddress,
+    pub data: *mut ::std::os::raw::c_void,
+    pub state: ENetPeerState,
+    pub channels: *mut ENetChannel,
+    pub channelCount: usize,
+    pub incomingBandwidth: enet_uint32,
+    pub outgoingBandwidth: enet_uint32,
+    pub incomingBandwidthThrottleEpoch: enet_uint32,
+    pub outgoingBandwidthThrottleEpoch: enet_uint32,
+    pub incomingDataTotal: enet_uint32,
+    pub outgoingDataTotal: enet_uint32,
+    pub lastSendTime: enet_uint32,
+    pub lastReceiveTime: enet_uint32,
+    pub nextTimeout: enet_uint32,
+    pub earliestTimeout: enet_uint32,
+    pub packetLossEpoch: enet_uint32,
+    pub packetsSent: enet_uint32,
+    pub packetsLost: enet_uint32,
+    pub packetLoss: enet_uint32,
+    pub packetLossVariance: enet_uint32,
+    pub packetThrottle: enet_uint32,
+    pub packetThrottleLimit: enet_uint32,
+    pub packetThrottleCounter: enet_uint32,
+    pub packetThrottleEpoch: enet_uint32,
+    pub packetThrottleAcceleration: enet_uint32,
+    pub packetThrottleDeceleration: enet_uint32,
+    pub packetThrottleInterval: enet_uint32,
+    pub pingInterval: enet_uint32,
+    pub timeoutLimit: enet_uint32,
+    pub timeoutMinimum: enet_uint32,
+    pub timeoutMaximum: enet_uint32,
+    pub lastRoundTripTime: enet_uint32,
+    pub lowestRoundTripTime: enet_uint32,
+    pub lastRoundTripTimeVariance: enet_uint32,
+    pub highestRoundTripTimeVariance: enet_uint32,
+    pub roundTripTime: enet_uint32,
+    pub roundTripTimeVariance: enet_uint32,
+    pub mtu: enet_uint32,
+    pub windowSize: enet_uint32,
+    pub reliableDataInTransit: enet_uint32,
+    pub outgoingReliableSequenceNumber: enet_uint16,
+    pub acknowledgements: ENetList,
+    pub sentReliableCommands: ENetList,
+    pub sentUnreliableCommands: ENetList,
+    pub outgoingCommands: ENetList,
+    pub dispatchedCommands: ENetList,
+    pub flags: enet_uint16,
+    pub reserved: enet_uint16,
+    pub incomingUnsequencedGroup: enet_uint16,
+    pub outgoingUnsequencedGroup: enet_uint16,
+    pub unsequencedWindow: [enet_uint32; 32usize],
+    pub eventData: enet_uint32,
+    pub totalWaitingData: usize,
+}
+pub type ENetPeer = _ENetPeer;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ENetCompressor {
+    pub context: *mut ::std::os::raw::c_void,
+    pub compress: ::std::option::Option<
+        unsafe extern "C" fn(
+            context: *mut ::std::os::raw::c_void,
+            inBuffers: *const ENetBuffer,
+            inBufferCount: usize,
+            inLimit: usize,
+            outData: *mut enet_uint8,
+            outLimit: usize,
+        ) -> usize,
+    >,
+    pub decompress: ::std::option::Option<
+        unsafe extern "C" fn(
+            context: *mut ::std::os::raw::c_void,
+            inData: *const enet_uint8,
+            inLimit: usize,
+            outData: *mut enet_uint8,
+            outLimit: usize,
+        ) -> usize,
+    >,
+    pub destroy: ::std::option::Option<unsafe extern "C" fn(context: *mut ::std::os::raw::c_void)>,
+}
+pub type ENetCompressor = _ENetCompressor;
+pub type ENetChecksumCallback = ::std::option::Option<
+    unsafe extern "C" fn(buffers: *const ENetBuffer, bufferCount: usize) -> enet_uint32,
+>;
+pub type ENetInterceptCallback = ::std::option::Option<
+    unsafe extern "C" fn(host: *mut _ENetHost, event: *mut _ENetEvent) -> ::std::os::raw::c_int,
+>;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _ENetHost {
+    pub socket: ENetSocket,
+    pub address: ENetAddress,
+    pub incomingBandwidth: enet_uint32,
+    pub outgoingBandwidth: enet_uint32,
+    pub bandwidthThrottleEpoch: enet_uint32,
+    pub mtu: enet_uint32,
+    pub randomSeed: enet_uint32,
+    pub recalculateBandwidthLimits: ::std::os::raw::c_int,
+    pub peers: *mut ENetPeer,
+    pub peerCount: usize,
+    pub channelLimit: usize,
+    pub serviceTime: enet_uint32,
+    pub dispatchQueue: ENetList,
+    pub continueSending: ::std::os::raw::c_int,
+    pub packetSize: usize,
+    pub headerFlags: enet_uint16,
+    pub commands: [ENetProtocol; 32usize],
+    pub commandCount: usize,
+    pub buffers: [ENetBuffer; 65usize],
+    pub bufferCount: usize,
+    pub checksum: ENetChecksumCallback,
+    pub compressor: ENetCompressor,
+    pub packetData: [[enet_uint8; 4096usize]; 2usize],
+    pub receivedAddress: ENetAddress,
+    pub receivedData: *mut enet_uint8,
+    pub receivedDataLength: usize,
+    pub totalSentData: enet_uint32,
+    pub totalSentPackets: enet_uint32,
+    pub totalReceivedData: enet_uint32,
+    pub totalReceivedPackets: enet_uint32,
+    pub intercept: ENetInterceptCallback,
+    pub connectedPeers: usize,
+    pub bandwidthLimitedPeers: usize,
+    pub duplicatePeers: usize,
+    pub maximumPacketSize: usize,
+    pub maximumWaitingData: usize,
+}
+pub type ENetHost = _ENetHost;
+pub const ENET_EVENT_TYPE_NONE: _ENetEventType = 0;
+pub const ENET_EVENT_TYPE_CONNECT: _ENetEventType = 1;
+pub const ENET_EVENT_TYPE_DISCONNECT: _ENetEventType = 2;
+pub const ENET_EVENT_TYPE_RECEIVE: _ENetEventType = 3;
+pub type _ENetEventType = ::std::os::raw::c_uint;
+pub use self::_ENetEventType as ENetEventType;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ENetEvent {
+    pub type_: ENetEventType,
+    pub peer: *mut ENetPeer,
+    pub channelID: enet_uint8,
+    pub data: enet_uint32,
+    pub packet: *mut ENetPacket,
+}
+pub type ENetEvent = _ENetEvent;
+unsafe extern "C" {
+    pub fn enet_initialize() -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn enet_initialize_with_callbacks(
+        version: ENetVersion,
+        inits: *const ENetCallbacks,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn enet_deinitialize();
+}
+unsafe extern "C" {
+    pub fn enet_linked_version() -> ENetVersion;
+}
+unsafe extern "C" {
+    pub fn enet_time_get() -> enet_uint32;
+}
+unsafe extern "C" {
+    pub fn enet_time_set(arg1: enet_uint32);
+}
+unsafe extern "C" {
+    pub fn enet_socket_create(arg1: ENetSocketType) -> ENetSocket;
+}
+unsafe extern "C" {
+    pub fn enet_socket_bind(arg1: ENetSocket, arg2: *const ENetAddress) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn enet_socket_get_address(
+        arg1: ENetSocket,
+        arg2: *mut ENetAddress,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn enet_socket_listen(
+        arg1: ENetSocket,
+        arg2: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn enet_socket_accept(arg1: ENetSocket, arg2: *mut ENetAddress) -> ENetSocket;
+}
+unsafe extern "C" {
+    pub fn enet_socket_connect(arg1: ENetSocket, arg2: *const ENetAddress)
+        -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn enet_socket_send(
+        arg1: ENetSocket,
+        arg2: *const ENetAddress,
+        arg3: *const ENetBuffer,
+        arg4: usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn enet_socket_receive(
+        arg1: ENetSocket,
+        arg2: *mut ENetAddress,
+        arg3: *mut ENetBuffer,
+        arg4: usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn enet_socket_wait(
+        arg1: ENetSocket,
+        arg2: *mut enet_uint32,
+        arg3: enet_uint32,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn enet_socket_set_option(
+        arg1: ENetSocket,
+        arg2: ENetSocketOption,
+        arg3: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn enet_socket_get_option(
+        arg1: ENetSocket,
+        arg2: ENetSocketOption,
+        arg3: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn enet_socket_shutdown(
+        arg1: ENetSocket,
+        arg2: ENetSocketShutdown,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn enet_socket_destroy(arg1: ENetSocket);
+}
+unsafe extern "C" {
+    pub fn enet_socketset_select(
+        arg1: ENetSocket,
+        arg2: *mut ENetSocketSet,
+        arg3: *mut ENetSocketSet,
+        arg4: enet_uint32,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn enet_address_set_host_ip(
+        address: *mut ENetAddress,
+        hostName: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn enet_address_set_host(
+        address: *mut ENetAddress,
+        hostName: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn enet_address_get_host_ip(
+        address: *const ENetAddress,
+        hostName: *mut ::std::os::raw::c_char,
+        nameLength: usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn enet_address_get_host(
+        address: *const ENetAddress,
+        hostName: *mut ::std::os::raw::c_char,
+        nameLength: usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn enet_packet_create(
+        arg1: *const ::std::os::raw::c_void,
+        arg2: usize,
+        arg3: enet_uint32,
+    ) -> *mut ENetPacket;
+}
+unsafe extern "C" {
+    pub fn enet_packet_destroy(arg1: *mut ENetPacket);
+}
+unsafe extern "C" {
+    pub fn enet_packet_resize(arg1: *mut ENetPacket, arg2: usize) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn enet_crc32(arg1: *const ENetBuffer, arg2: usize) -> enet_uint32;
+}
+unsafe extern "C" {
+    pub fn enet_host_create(
+        arg1: *const ENetAddress,
+        arg2: usize,
+        arg3: usize,
+        arg4: enet_uint32,
+        arg5: enet_uint32,
+    ) -> *mut ENetHost;
+}
+unsafe extern "C" {
+    pub fn enet_host_destroy(arg1: *mut ENetHost);
+}
+unsafe extern "C" {
+    pub fn enet_host_connect(
+        arg1: *mut ENetHost,
+        arg2: *const ENetAddress,
+        arg3: usize,
+        arg4: enet_uint32,
+    ) -> *mut ENetPeer;
+}
+unsafe extern "C" {
+    pub fn enet_host_check_events(
+        arg1: *mut ENetHost,
+        arg2: *mut ENetEvent,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn enet_host_service(
+        arg1: *mut ENetHost,
+        arg2: *mut ENetEvent,
+        arg3: enet_uint32,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn enet_host_flush(arg1: *mut ENetHost);
+}
+unsafe extern "C" {
+    pub fn enet_host_broadcast(arg1: *mut ENetHost, arg2: enet_uint8, arg3: *mut ENetPacket);
+}
+unsafe extern "C" {
+    pub fn enet_host_compress(arg1: *mut ENetHost, arg2: *const ENetCompressor);
+}
+unsafe extern "C" {
+    pub fn enet_host_compress_with_range_coder(host: *mut ENetHost) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn enet_host_channel_limit(arg1: *mut ENetHost, arg2: usize);
+}
+unsafe extern "C" {
+    pub fn enet_host_bandwidth_limit(arg1: *mut ENetHost, arg2: enet_uint32, arg3: enet_uint32);
+}
+unsafe extern "C" {
+    pub fn enet_host_bandwidth_throttle(arg1: *mut ENetHost);
+}
+unsafe extern "C" {
+    pub fn enet_host_random_seed() -> enet_uint32;
+}
+unsafe extern "C" {
+    pub fn enet_peer_send(
+        arg1: *mut ENetPeer,
+        arg2: enet_uint8,
+        arg3: *mut ENetPacket,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn enet_peer_receive(arg1: *mut ENetPeer, channelID: *mut enet_uint8) -> *mut ENetPacket;
+}
+unsafe extern "C" {
+    pub fn enet_peer_ping(arg1: *mut ENetPeer);
+}
+unsafe extern "C" {
+    pub fn enet_peer_ping_interval(arg1: *mut ENetPeer, arg2: enet_uint32);
+}
+unsafe extern "C" {
+    pub fn enet_peer_timeout(
+        arg1: *mut ENetPeer,
+        arg2: enet_uint32,
+        arg3: enet_uint32,
+        arg4: enet_uint32,
+    );
+}
+unsafe extern "C" {
+    pub fn enet_peer_reset(arg1: *mut ENetPeer);
+}
+unsafe extern "C" {
+    pub fn enet_peer_disconnect(arg1: *mut ENetPeer, arg2: enet_uint32);
+}
+unsafe extern "C" {
+    pub fn enet_peer_disconnect_now(arg1: *mut ENetPeer, arg2: enet_uint32);
+}
+unsafe extern "C" {
+    pub fn enet_peer_disconnect_later(arg1: *mut ENetPeer, arg2: enet_uint32);
+}
+unsafe extern "C" {
+    pub fn enet_peer_throttle_configure(
+        arg1: *mut ENetPeer,
+        arg2: enet_uint32,
+        arg3: enet_uint32,
+        arg4: enet_uint32,
+    );
+}
+unsafe extern "C" {
+    pub fn enet_peer_throttle(arg1: *mut ENetPeer, arg2: enet_uint32) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn enet_peer_reset_queues(arg1: *mut ENetPeer);
+}
+unsafe extern "C" {
+    pub fn enet_peer_setup_outgoing_command(arg1: *mut ENetPeer, arg2: *mut ENetOutgoingCommand);
+}
+unsafe extern "C" {
+    pub fn enet_peer_queue_outgoing_command(
+        arg1: *mut ENetPeer,
+        arg2: *const ENetProtocol,
+        arg3: *mut ENetPacket,
+        arg4: enet_uint32,
+        arg5: enet_uint16,
+    ) -> *mut ENetOutgoingCommand;
+}
+unsafe extern "C" {
+    pub fn enet_peer_queue_incoming_command(
+        arg1: *mut ENetPeer,
+        arg2: *const ENetProtocol,
+        arg3: *const ::std::os::raw::c_void,
+        arg4: usize,
+        arg5: enet_uint32,
+        arg6: enet_uint32,
+    ) -> *mut ENetIncomingCommand;
+}
+unsafe extern "C" {
+    pub fn enet_peer_queue_acknowledgement(
+        arg1: *mut ENetPeer,
+        arg2: *const ENetProtocol,
+        arg3: enet_uint16,
+    ) -> *mut ENetAcknowledgement;
+}
+unsafe extern "C" {
+    pub fn enet_peer_dispatch_incoming_unreliable_commands(
+        arg1: *mut ENetPeer,
+        arg2: *mut ENetChannel,
+        arg3: *mut ENetIncomingCommand,
+    );
+}
+unsafe extern "C" {
+    pub fn enet_peer_dispatch_incoming_reliable_commands(
+        arg1: *mut ENetPeer,
+        arg2: *mut ENetChannel,
+        arg3: *mut ENetIncomingCommand,
+    );
+}
+unsafe extern "C" {
+    pub fn enet_peer_on_connect(arg1: *mut ENetPeer);
+}
+unsafe extern "C" {
+    pub fn enet_peer_on_disconnect(arg1: *mut ENetPeer);
+}
+unsafe extern "C" {
+    pub fn enet_range_coder_create() -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    pub fn enet_range_coder_destroy(arg1: *mut ::std::os::raw::c_void);
+}
+unsafe extern "C" {
+    pub fn enet_range_coder_compress(
+        arg1: *mut ::std::os::raw::c_void,
+        arg2: *const ENetBuffer,
+        arg3: usize,
+        arg4: usize,
+        arg5: *mut enet_uint8,
+        arg6: usize,
+    ) -> usize;
+}
+unsafe extern "C" {
+    pub fn enet_range_coder_decompress(
+        arg1: *mut ::std::os::raw::c_void,
+        arg2: *const enet_uint8,
+        arg3: usize,
+        arg4: *mut enet_uint8,
+        arg5: usize,
+    ) -> usize;
+}
+unsafe extern "C" {
+    pub fn enet_protocol_command_size(arg1: enet_uint8) -> usize;
+}
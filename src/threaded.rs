@@ -0,0 +1,488 @@
+/*
+ * threaded.rs
+ *
+ * ENet host serviced on a dedicated thread, driven through channels
+ */
+
+use std::{
+    collections::{HashMap, VecDeque},
+    panic,
+    sync::{
+        mpsc::{self, Receiver, SyncSender, TryRecvError},
+        Arc,
+        Condvar,
+        Mutex,
+        MutexGuard,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    builder::HostBuilder,
+    context::Enet,
+    enet::{ENetAddress, ENetEvent},
+    error::Error,
+    event::Event,
+    host::Host,
+    packet::Packet,
+    peer::Peer,
+};
+
+/**
+ * Configuration of a [`ThreadedHost`].
+ */
+#[derive(Debug)]
+pub struct ThreadedConfig {
+    host: HostBuilder,
+    command_capacity: usize,
+    service_timeout: Duration,
+}
+
+impl ThreadedConfig {
+    /**
+     * Creates a configuration for a host built from `host`, queueing up to
+     * 1024 commands and servicing the host at least every millisecond.
+     */
+    pub fn new(host: HostBuilder) -> ThreadedConfig {
+        ThreadedConfig { host, command_capacity: 1024, service_timeout: Duration::from_millis(1) }
+    }
+
+    /**
+     * Number of commands that can be queued for the service thread before
+     * handles block on sending more.
+     */
+    pub fn command_capacity(mut self, command_capacity: usize) -> ThreadedConfig {
+        self.command_capacity = command_capacity;
+        self
+    }
+
+    /**
+     * How long the service thread waits in `enet_host_service`, which bounds
+     * how long a queued command waits before it is carried out.
+     */
+    pub fn service_timeout(mut self, service_timeout: Duration) -> ThreadedConfig {
+        self.service_timeout = service_timeout;
+        self
+    }
+}
+
+/**
+ * A peer of a [`ThreadedHost`], identifying one connection.
+ *
+ * Peers are reused once disconnected, so besides the id of the peer this
+ * holds the `connectID` of the connection; commands for a connection that
+ * has ended fail with [`Error::InvalidPeer`] instead of reaching whichever
+ * client took its place.
+ *
+ * [`Error::InvalidPeer`]: crate::error::Error::InvalidPeer
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ThreadedPeer {
+    id: usize,
+    connect_id: u32,
+}
+
+impl ThreadedPeer {
+    /** index of the peer in the host, see `Peer::id` */
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /** `connectID` of the connection, see `Peer::connect_id` */
+    pub fn connect_id(&self) -> u32 {
+        self.connect_id
+    }
+}
+
+/**
+ * An event of a [`ThreadedHost`], carrying a copy of the received data since
+ * packets cannot leave the service thread.
+ */
+#[derive(Debug)]
+pub enum ThreadedEvent {
+    Connect {
+        /** the peer which connected */
+        peer: ThreadedPeer,
+        /** address of the peer */
+        address: ENetAddress,
+        data: u32,
+    },
+    Disconnect {
+        /** the peer which disconnected */
+        peer: ThreadedPeer,
+        data: u32,
+    },
+    Receive {
+        /** the peer which sent the packet */
+        peer: ThreadedPeer,
+        channel: u8,
+        data: Vec<u8>,
+    },
+    /** a queued command failed, or servicing the host did */
+    Error(Error),
+}
+
+/** work queued for the service thread */
+enum Command {
+    Send { peer: ThreadedPeer, channel: u8, data: Vec<u8>, flags: u32 },
+    Broadcast { channel: u8, data: Vec<u8>, flags: u32 },
+    Connect { address: ENetAddress, channel_count: usize, data: u32, reply: SyncSender<Result<ThreadedPeer, Error>> },
+    Disconnect { peer: ThreadedPeer, data: u32 },
+    DisconnectNow { peer: ThreadedPeer, data: u32 },
+    Shutdown,
+}
+
+/**
+ * A cloneable `Send + Sync` handle to a [`ThreadedHost`].
+ *
+ * Commands are queued for the service thread, and sending blocks while the
+ * queue is full. Events are shared between all handles: each event goes to
+ * whichever handle receives first, and they queue up without bound until
+ * received. All methods fail with [`Error::HostStopped`] once the service
+ * thread is gone.
+ *
+ * [`Error::HostStopped`]: crate::error::Error::HostStopped
+ */
+#[derive(Debug, Clone)]
+pub struct HostHandle {
+    commands: SyncSender<Command>,
+    events: Arc<Events>,
+}
+
+/**
+ * Events shared between all handles. Waiting releases the lock, so handles
+ * never block each other for longer than a push or pop.
+ */
+#[derive(Debug, Default)]
+struct Events {
+    queue: Mutex<EventQueue>,
+    ready: Condvar,
+}
+
+#[derive(Debug, Default)]
+struct EventQueue {
+    events: VecDeque<ThreadedEvent>,
+    /** set once the service thread is gone, so no more events will come */
+    stopped: bool,
+}
+
+impl Events {
+    fn lock(&self) -> MutexGuard<'_, EventQueue> {
+        self.queue.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn push(&self, event: ThreadedEvent) {
+        self.lock().events.push_back(event);
+        self.ready.notify_one();
+    }
+
+    fn stop(&self) {
+        self.lock().stopped = true;
+        self.ready.notify_all();
+    }
+}
+
+impl HostHandle {
+    fn command(&self, command: Command) -> Result<(), Error> {
+        self.commands.send(command).map_err(|_| Error::HostStopped)
+    }
+
+    /**
+     * Queues `data` to be sent to a peer as a packet.
+     * ### Parameters
+     * ⠀⠀⠀⠀⠀*peer: connection to send to \
+     * ⠀⠀⠀⠀⠀channel: channel on which to send \
+     * ⠀⠀⠀⠀⠀data: contents of the packet \
+     * ⠀⠀⠀⠀⠀flags: `ENetPacketFlag` bits of the packet*
+     */
+    pub fn send(&self, peer: ThreadedPeer, channel: u8, data: Vec<u8>, flags: u32) -> Result<(), Error> {
+        self.command(Command::Send { peer, channel, data, flags })
+    }
+
+    /**
+     * Queues `data` to be sent to all peers as a packet.
+     * ### Parameters
+     * ⠀⠀⠀⠀⠀*channel: channel on which to broadcast \
+     * ⠀⠀⠀⠀⠀data: contents of the packet \
+     * ⠀⠀⠀⠀⠀flags: `ENetPacketFlag` bits of the packet*
+     */
+    pub fn broadcast(&self, channel: u8, data: Vec<u8>, flags: u32) -> Result<(), Error> {
+        self.command(Command::Broadcast { channel, data, flags })
+    }
+
+    /**
+     * Initiates a connection to a foreign host, returning the peer once the
+     * service thread has started it. The connection completes with
+     * a [`ThreadedEvent::Connect`] for the peer.
+     * ### Parameters
+     * ⠀⠀⠀⠀⠀*address: destination for the connection \
+     * ⠀⠀⠀⠀⠀channel_count: number of channels to allocate \
+     * ⠀⠀⠀⠀⠀data: user data supplied to the receiving host*
+     */
+    pub fn connect(&self, address: ENetAddress, channel_count: usize, data: u32) -> Result<ThreadedPeer, Error> {
+        let (reply, result) = mpsc::sync_channel(1);
+        self.command(Command::Connect { address, channel_count, data, reply })?;
+        result.recv().map_err(|_| Error::HostStopped)?
+    }
+
+    /** requests a disconnection from a peer, see `Peer::disconnect` */
+    pub fn disconnect(&self, peer: ThreadedPeer, data: u32) -> Result<(), Error> {
+        self.command(Command::Disconnect { peer, data })
+    }
+
+    /** forcefully disconnects a peer, see `Peer::disconnect_now` */
+    pub fn disconnect_now(&self, peer: ThreadedPeer, data: u32) -> Result<(), Error> {
+        self.command(Command::DisconnectNow { peer, data })
+    }
+
+    /** waits for the next event */
+    pub fn recv(&self) -> Result<ThreadedEvent, Error> {
+        let mut queue = self.events.lock();
+        loop {
+            match queue.events.pop_front() {
+                Some(event) => return Ok(event),
+                None if queue.stopped => return Err(Error::HostStopped),
+                None => queue = self.events.ready.wait(queue).unwrap_or_else(|e| e.into_inner()),
+            }
+        }
+    }
+
+    /** gives the next event if there is one, without waiting */
+    pub fn try_recv(&self) -> Result<Option<ThreadedEvent>, Error> {
+        let mut queue = self.events.lock();
+        match queue.events.pop_front() {
+            Some(event) => Ok(Some(event)),
+            None if queue.stopped => Err(Error::HostStopped),
+            None => Ok(None),
+        }
+    }
+
+    /** waits at most `timeout` for the next event */
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<Option<ThreadedEvent>, Error> {
+        let deadline = Instant::now() + timeout;
+        let mut queue = self.events.lock();
+        loop {
+            match queue.events.pop_front() {
+                Some(event) => return Ok(Some(event)),
+                None if queue.stopped => return Err(Error::HostStopped),
+                None => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return Ok(None);
+                    }
+
+                    queue = self.events.ready.wait_timeout(queue, deadline - now).unwrap_or_else(|e| e.into_inner()).0;
+                }
+            }
+        }
+    }
+}
+
+/**
+ * A host running `enet_host_service` on a thread of its own, for programs
+ * whose logic runs on other threads. `ENetHost` is not thread-safe, so it
+ * never leaves the service thread; everything goes through [`HostHandle`]s.
+ *
+ * Dropping the host, or calling [`ThreadedHost::join`], disconnects all
+ * peers, stops the service thread and waits for it.
+ */
+#[derive(Debug)]
+pub struct ThreadedHost {
+    handle: HostHandle,
+    address: ENetAddress,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl ThreadedHost {
+    /**
+     * Builds the host from `config` and starts servicing it on a new thread.
+     */
+    pub fn spawn(config: ThreadedConfig) -> Result<ThreadedHost, Error> {
+        let enet = Enet::new()?;
+        let host: Host = config.host.build(&enet)?;
+        let address = unsafe { (*host.as_raw()).address };
+        let timeout = config.service_timeout.as_millis().min(u32::MAX as u128) as u32;
+        let (commands, command_queue) = mpsc::sync_channel(config.command_capacity);
+        let events = Arc::new(Events::default());
+        let service = Service { host, enet, events: events.clone(), connections: HashMap::new() };
+
+        let thread = thread::Builder::new()
+            .name("enet-service".into())
+            .spawn(move || service.run(command_queue, timeout))
+            .map_err(Error::Socket)?;
+
+        Ok(ThreadedHost {
+            handle: HostHandle { commands, events },
+            address,
+            thread: Some(thread),
+        })
+    }
+
+    /** the address the host is bound to, e.g. to learn the port picked for port 0 */
+    pub fn address(&self) -> ENetAddress {
+        self.address
+    }
+
+    /** a new handle to the host */
+    pub fn handle(&self) -> HostHandle {
+        self.handle.clone()
+    }
+
+    /**
+     * Disconnects all peers, stops the service thread and waits for it,
+     * resuming its panic if it panicked.
+     */
+    pub fn join(mut self) {
+        if let Err(payload) = self.shutdown() {
+            panic::resume_unwind(payload);
+        }
+    }
+
+    fn shutdown(&mut self) -> thread::Result<()> {
+        match self.thread.take() {
+            Some(thread) => {
+                // fails if the thread is already gone, which join reports
+                let _ = self.handle.commands.send(Command::Shutdown);
+                thread.join()
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for ThreadedHost {
+    fn drop(&mut self) {
+        let _ = self.shutdown();
+    }
+}
+
+/** state of the service thread */
+struct Service {
+    host: Host,
+    enet: Enet,
+    events: Arc<Events>,
+    /**
+     * `connectID` of the connection of each peer in use, kept here since ENet
+     * resets it before reporting the disconnection
+     */
+    connections: HashMap<usize, u32>,
+}
+
+impl Service {
+    fn run(mut self, commands: Receiver<Command>, timeout: u32) {
+        loop {
+            loop {
+                match commands.try_recv() {
+                    Ok(Command::Shutdown) | Err(TryRecvError::Disconnected) => return self.shutdown(),
+                    Ok(command) => self.execute(command),
+                    Err(TryRecvError::Empty) => break,
+                }
+            }
+
+            let mut event = self.host.service_raw(timeout);
+            loop {
+                match event {
+                    Ok(Some(raw)) => self.dispatch(&raw),
+                    Ok(None) => break,
+                    Err(e) => {
+                        self.emit(ThreadedEvent::Error(e));
+                        break;
+                    }
+                }
+                event = self.host.check_events_raw();
+            }
+        }
+    }
+
+    fn execute(&mut self, command: Command) {
+        let result = match command {
+            Command::Send { peer, channel, data, flags } => Packet::from_vec(&self.enet, data, flags).and_then(|packet| {
+                self.peer(peer)?.send(channel, packet)
+            }),
+            Command::Broadcast { channel, data, flags } => {
+                Packet::from_vec(&self.enet, data, flags).map(|packet| self.host.broadcast(channel, packet))
+            }
+            Command::Connect { address, channel_count, data, reply } => {
+                let peer = self.host.connect(&address, channel_count, data).map(|peer| ThreadedPeer {
+                    id: peer.id(),
+                    connect_id: peer.connect_id(),
+                });
+                if let Ok(peer) = &peer {
+                    self.connections.insert(peer.id, peer.connect_id);
+                }
+
+                // the handle may have stopped waiting
+                let _ = reply.send(peer);
+                Ok(())
+            }
+            Command::Disconnect { peer, data } => self.peer(peer).map(|mut peer| peer.disconnect(data)),
+            Command::DisconnectNow { peer, data } => self.peer(peer).map(|mut peer| peer.disconnect_now(data)).map(|_| {
+                // the peer is reset without a disconnect event ending the connection
+                self.connections.remove(&peer.id);
+            }),
+            Command::Shutdown => Ok(()),
+        };
+
+        if let Err(e) = result {
+            self.emit(ThreadedEvent::Error(e));
+        }
+    }
+
+    /**
+     * The peer of a connection, if it has not ended. ENet clears `connectID`
+     * when it resets a peer and sets the new one on an incoming connection,
+     * so the peer itself must still agree.
+     */
+    fn peer(&mut self, target: ThreadedPeer) -> Result<Peer<'_>, Error> {
+        if self.connections.get(&target.id) != Some(&target.connect_id) {
+            return Err(Error::InvalidPeer(target.id));
+        }
+
+        self.host
+            .peer_by_id(target.id)
+            .filter(|peer| peer.connect_id() == target.connect_id)
+            .ok_or(Error::InvalidPeer(target.id))
+    }
+
+    fn dispatch(&mut self, raw: &ENetEvent) {
+        let event = match unsafe { Event::<()>::from_raw(raw) } {
+            Some(Event::Connect { peer, data }) => {
+                let connect_id = peer.connect_id();
+                self.connections.insert(peer.id(), connect_id);
+                ThreadedEvent::Connect { peer: ThreadedPeer { id: peer.id(), connect_id }, address: peer.address(), data }
+            }
+            Some(Event::Disconnect { peer, data }) => {
+                let connect_id = self.connections.remove(&peer.id()).unwrap_or_else(|| peer.connect_id());
+                ThreadedEvent::Disconnect { peer: ThreadedPeer { id: peer.id(), connect_id }, data }
+            }
+            Some(Event::Receive { peer, channel, packet }) => {
+                let connect_id = self.connections.get(&peer.id()).copied().unwrap_or_else(|| peer.connect_id());
+                ThreadedEvent::Receive { peer: ThreadedPeer { id: peer.id(), connect_id }, channel, data: packet.to_vec() }
+            }
+            None => return,
+        };
+
+        self.emit(event);
+    }
+
+    fn emit(&self, event: ThreadedEvent) {
+        self.events.push(event);
+    }
+
+    /** notifies connected peers before the host is destroyed */
+    fn shutdown(mut self) {
+        for mut peer in self.host.peers() {
+            peer.disconnect_now(0);
+        }
+
+        self.host.flush();
+    }
+}
+
+impl Drop for Service {
+    /** wakes up waiting handles when the thread stops, even by panicking */
+    fn drop(&mut self) {
+        self.events.stop();
+    }
+}
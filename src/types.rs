@@ -1,4 +1,6 @@
-use libc::{c_char, c_uchar, c_ushort, c_uint, c_ulonglong};
+#[cfg(unix)]
+use libc::c_int;
+use libc::{c_uchar, c_ushort, c_uint};
 
 /**
  * types.rs
@@ -10,5 +12,14 @@ pub type enet_uint8 = c_uchar;
 pub type enet_uint16 = c_ushort;
 pub type enet_uint32 = c_uint;
 
-pub type SOCKET = c_ulonglong;
-pub const INVALID_SOCKET: c_char = -1;
\ No newline at end of file
+/** a file descriptor on Unix, as in unix.h */
+#[cfg(unix)]
+pub type SOCKET = c_int;
+#[cfg(unix)]
+pub const INVALID_SOCKET: SOCKET = -1;
+
+/** a Winsock `SOCKET`, which is a `UINT_PTR` */
+#[cfg(windows)]
+pub type SOCKET = usize;
+#[cfg(windows)]
+pub const INVALID_SOCKET: SOCKET = !0;
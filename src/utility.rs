@@ -1,5 +1,7 @@
+#[cfg(windows)]
 use libc::c_uint;
 
+#[cfg(windows)]
 use crate::types::SOCKET;
 
 /**
@@ -29,9 +31,19 @@ macro_rules! ENET_DIFFERENCE {
     };
 }
 
+#[cfg(unix)]
+pub use libc::fd_set;
+
+/** number of sockets a Winsock `fd_set` holds */
+#[cfg(windows)]
+pub const FD_SETSIZE: usize = 64;
+
+/** the Winsock `fd_set`, a counted array rather than a bitmap */
+#[cfg(windows)]
 #[repr(C)]
+#[derive(Copy, Clone)]
 pub struct fd_set {
     pub fd_count: c_uint,
-    pub fd_array: [SOCKET; 64usize],
+    pub fd_array: [SOCKET; FD_SETSIZE],
 }
 
@@ -0,0 +1,57 @@
+/*
+ * address.rs
+ *
+ * checks the ENetAddress conversions against ENet's own, which keeps hosts in network order
+ */
+
+use std::{
+    convert::TryFrom,
+    net::{Ipv4Addr, SocketAddr, SocketAddrV4},
+};
+
+use enet_rs::{enet::ENetAddress, Enet, Error};
+
+/** 1.2.3.4:5 as ENet parses it with enet_address_set_host */
+fn enet_address() -> ENetAddress {
+    let _enet = Enet::new().unwrap();
+    let address = ENetAddress::resolve("1.2.3.4", 5).unwrap();
+    assert_eq!(address.host.to_ne_bytes(), [1, 2, 3, 4]);
+    address
+}
+
+/** checks `address` is 1.2.3.4:5 as ENet prints it with enet_address_get_host_ip */
+fn assert_enet_agrees(address: ENetAddress) {
+    assert_eq!(address, enet_address());
+    assert_eq!(address.host_ip().unwrap(), "1.2.3.4");
+    assert_eq!(address.port, 5);
+}
+
+#[test]
+fn new_matches_enet() {
+    let address = ENetAddress::new(Ipv4Addr::new(1, 2, 3, 4), 5);
+    assert_enet_agrees(address);
+    assert_eq!(enet_address().ip(), Ipv4Addr::new(1, 2, 3, 4));
+}
+
+#[test]
+fn socket_addr_matches_enet() {
+    let socket_addr = SocketAddrV4::new(Ipv4Addr::new(1, 2, 3, 4), 5);
+    assert_enet_agrees(ENetAddress::from(socket_addr));
+    assert_enet_agrees(ENetAddress::try_from(SocketAddr::V4(socket_addr)).unwrap());
+    assert_eq!(SocketAddrV4::from(enet_address()), socket_addr);
+    assert_eq!(SocketAddr::from(enet_address()), SocketAddr::V4(socket_addr));
+
+    assert!(matches!(
+        ENetAddress::try_from("[::1]:5".parse::<SocketAddr>().unwrap()),
+        Err(Error::UnsupportedAddress)
+    ));
+}
+
+#[test]
+fn string_matches_enet() {
+    assert_enet_agrees("1.2.3.4:5".parse().unwrap());
+    assert_eq!(enet_address().to_string(), "1.2.3.4:5");
+
+    assert!(matches!("1.2.3.4".parse::<ENetAddress>(), Err(Error::AddressResolution)));
+    assert!(matches!("localhost:5".parse::<ENetAddress>(), Err(Error::AddressResolution)));
+}
@@ -0,0 +1,42 @@
+/*
+ * bindings.rs
+ *
+ * checks the pregenerated src/sys/bindings.rs against what bindgen generates
+ */
+
+// enums are signed with MSVC, and the pregenerated bindings are not generated there
+#![cfg(all(feature = "bindgen", not(target_env = "msvc")))]
+
+use std::{env, fs, path::Path};
+
+#[test]
+fn pregenerated_bindings_are_current() {
+    let generated = fs::read_to_string(concat!(env!("OUT_DIR"), "/bindings.rs")).unwrap();
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/sys/bindings.rs");
+
+    // ENET_RS_BLESS=1 cargo test --features bindgen --test bindings
+    if env::var_os("ENET_RS_BLESS").is_some() {
+        fs::write(&path, &generated).unwrap();
+        return;
+    }
+
+    let pregenerated = fs::read_to_string(&path).unwrap();
+    let difference = generated
+        .lines()
+        .zip(pregenerated.lines())
+        .position(|(generated, pregenerated)| generated != pregenerated)
+        .or_else(|| {
+            let (generated, pregenerated) = (generated.lines().count(), pregenerated.lines().count());
+            (generated != pregenerated).then(|| generated.min(pregenerated))
+        });
+
+    if let Some(line) = difference {
+        panic!(
+            "src/sys/bindings.rs differs from the generated bindings at line {}:\n  generated:    {}\n  pregenerated: {}\n\
+             rerun with ENET_RS_BLESS=1 to update it",
+            line + 1,
+            generated.lines().nth(line).unwrap_or("<end of file>"),
+            pregenerated.lines().nth(line).unwrap_or("<end of file>"),
+        );
+    }
+}
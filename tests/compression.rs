@@ -0,0 +1,80 @@
+/*
+ * compression.rs
+ *
+ * round trips datagrams through the Rust compressors
+ */
+
+#![cfg(any(feature = "lz4", feature = "zstd"))]
+
+use enet_rs::Compressor;
+
+/** a datagram split over several buffers, as ENet hands them out */
+fn datagram() -> Vec<Vec<u8>> {
+    vec![b"header".to_vec(), b"repetitive payload ".repeat(40), vec![7; 100]]
+}
+
+/** bytes that do not compress, from a xorshift generator */
+fn noise(length: usize) -> Vec<u8> {
+    let mut state = 0x2545_f491_u32;
+    (0..length)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state as u8
+        })
+        .collect()
+}
+
+fn round_trip<C: Compressor>(compressor: &mut C) {
+    let buffers = datagram();
+    let input: Vec<&[u8]> = buffers.iter().map(Vec::as_slice).collect();
+    let original = buffers.concat();
+
+    let mut compressed = [0; 1400];
+    let length = compressor.compress(&input, &mut compressed).unwrap();
+    assert!(length < original.len());
+
+    let mut decompressed = vec![0; original.len()];
+    let length = compressor.decompress(&compressed[..length], &mut decompressed).unwrap();
+    assert_eq!(&decompressed[..length], &original[..]);
+}
+
+fn does_not_fit<C: Compressor>(compressor: &mut C) {
+    // incompressible data cannot shrink into less space than it takes
+    let data = noise(512);
+    let mut compressed = [0; 256];
+    assert_eq!(compressor.compress(&[&data], &mut compressed), None);
+
+    // neither can a datagram decompress into a buffer too small for it
+    let buffers = datagram();
+    let input: Vec<&[u8]> = buffers.iter().map(Vec::as_slice).collect();
+    let mut compressed = [0; 1400];
+    let length = compressor.compress(&input, &mut compressed).unwrap();
+    let mut decompressed = [0; 16];
+    assert_eq!(compressor.decompress(&compressed[..length], &mut decompressed), None);
+}
+
+#[cfg(feature = "lz4")]
+#[test]
+fn lz4_round_trip() {
+    round_trip(&mut enet_rs::compressor::Lz4Compressor::new());
+}
+
+#[cfg(feature = "lz4")]
+#[test]
+fn lz4_does_not_fit() {
+    does_not_fit(&mut enet_rs::compressor::Lz4Compressor::new());
+}
+
+#[cfg(feature = "zstd")]
+#[test]
+fn zstd_round_trip() {
+    round_trip(&mut enet_rs::compressor::ZstdCompressor::new(0).unwrap());
+}
+
+#[cfg(feature = "zstd")]
+#[test]
+fn zstd_does_not_fit() {
+    does_not_fit(&mut enet_rs::compressor::ZstdCompressor::new(0).unwrap());
+}
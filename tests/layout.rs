@@ -0,0 +1,362 @@
+/*
+ * layout.rs
+ *
+ * checks the Rust definitions against the sizes and offsets reported by src/layout.c
+ */
+
+#![allow(non_snake_case)]
+
+use std::{
+    collections::HashMap,
+    ffi::CStr,
+    mem::{self, MaybeUninit},
+    os::raw::c_char,
+    ptr,
+    slice,
+};
+
+use enet_rs::{
+    callbacks::ENetCallbacks,
+    enet::*,
+    header::{ENetBuffer, ENetSocket, ENetSocketSet},
+    list::{ENetList, ENetListNode},
+    protocol::*,
+    types::{enet_uint16, enet_uint32, enet_uint8},
+};
+
+#[repr(C)]
+struct EnetRsLayout {
+    type_: *const c_char,
+    field: *const c_char,
+    size: usize,
+    offset: usize,
+}
+
+extern "C" {
+    static enet_rs_layouts: EnetRsLayout;
+    static enet_rs_layout_count: usize;
+}
+
+/** (size, offset) of every C type and field, the type itself having an empty field name */
+fn c_layouts() -> HashMap<(String, String), (usize, usize)> {
+    let layouts = unsafe { slice::from_raw_parts(ptr::addr_of!(enet_rs_layouts), enet_rs_layout_count) };
+
+    layouts
+        .iter()
+        .map(|layout| {
+            let name = |s| unsafe { CStr::from_ptr(s) }.to_string_lossy().into_owned();
+            ((name(layout.type_), name(layout.field)), (layout.size, layout.offset))
+        })
+        .collect()
+}
+
+fn size_of_pointee<T>(_: *const T) -> usize {
+    mem::size_of::<T>()
+}
+
+/** collects every mismatch instead of stopping at the first one */
+struct Checker {
+    c: HashMap<(String, String), (usize, usize)>,
+    mismatches: Vec<String>,
+}
+
+impl Checker {
+    fn new() -> Checker {
+        Checker { c: c_layouts(), mismatches: Vec::new() }
+    }
+
+    fn check(&mut self, type_: &str, field: &str, size: usize, offset: usize) {
+        let name = if field.is_empty() { type_.to_string() } else { format!("{}.{}", type_, field) };
+
+        match self.c.get(&(type_.to_string(), field.to_string())) {
+            Some(&c) if c == (size, offset) => {}
+            Some(&(c_size, c_offset)) => self.mismatches.push(format!(
+                "{}: Rust has size {} at offset {}, C has size {} at offset {}",
+                name, size, offset, c_size, c_offset
+            )),
+            None => self.mismatches.push(format!("{}: missing from layout.c", name)),
+        }
+    }
+
+    fn finish(self) {
+        assert!(self.mismatches.is_empty(), "layout mismatches:\n{}", self.mismatches.join("\n"));
+    }
+}
+
+/** checks a type, then each of its fields, under the C field name where it differs */
+macro_rules! layout {
+    ($checker:expr, $ty:ident) => {
+        $checker.check(stringify!($ty), "", mem::size_of::<$ty>(), 0)
+    };
+    ($checker:expr, $ty:ident { $($field:ident $(as $c:literal)?),* $(,)? }) => {{
+        layout!($checker, $ty);
+        $(
+            let value = MaybeUninit::<$ty>::uninit();
+            let size = size_of_pointee(unsafe { ptr::addr_of!((*value.as_ptr()).$field) });
+            let field = [$($c,)? stringify!($field)][0];
+            $checker.check(stringify!($ty), field, size, mem::offset_of!($ty, $field));
+        )*
+    }};
+}
+
+#[test]
+fn types_and_header() {
+    let mut checker = Checker::new();
+
+    layout!(checker, enet_uint8);
+    layout!(checker, enet_uint16);
+    layout!(checker, enet_uint32);
+    layout!(checker, ENetSocket);
+    layout!(checker, ENetSocketSet);
+    layout!(checker, ENetBuffer { data, dataLength });
+
+    checker.finish();
+}
+
+#[test]
+fn list() {
+    let mut checker = Checker::new();
+
+    layout!(checker, ENetListNode { next, previous });
+    layout!(checker, ENetList { sentinel });
+
+    checker.finish();
+}
+
+#[test]
+fn callbacks() {
+    let mut checker = Checker::new();
+
+    layout!(checker, ENetCallbacks { malloc, free, no_memory });
+
+    checker.finish();
+}
+
+#[test]
+fn protocol() {
+    let mut checker = Checker::new();
+
+    layout!(checker, ENetProtocolHeader { peerID, sentTime });
+    layout!(checker, ENetProtocolCommandHeader { command, channelID, reliableSequenceNumber });
+    layout!(checker, ENetProtocolAcknowledge { header, receivedReliableSequenceNumber, receivedSentTime });
+    layout!(checker, ENetProtocolConnect {
+        header,
+        outgoingPeerID,
+        incomingSessionID,
+        outgoingSessionID,
+        mtu,
+        windowSize,
+        channelCount,
+        incomingBandwidth,
+        outgoingBandwidth,
+        packetThrottleInterval,
+        packetThrottleAcceleration,
+        packetThrottleDeceleration,
+        connectID,
+        data,
+    });
+    layout!(checker, ENetProtocolVerifyConnect {
+        header,
+        outgoingPeerID,
+        incomingSessionID,
+        outgoingSessionID,
+        mtu,
+        windowSize,
+        channelCount,
+        incomingBandwidth,
+        outgoingBandwidth,
+        packetThrottleInterval,
+        packetThrottleAcceleration,
+        packetThrottleDeceleration,
+        connectID,
+    });
+    layout!(checker, ENetProtocolBandwidthLimit { header, incomingBandwidth, outgoingBandwidth });
+    layout!(checker, ENetProtocolThrottleConfigure {
+        header,
+        packetThrottleInterval,
+        packetThrottleAcceleration,
+        packetThrottleDeceleration,
+    });
+    layout!(checker, ENetProtocolDisconnect { header, data });
+    layout!(checker, ENetProtocolPing { header });
+    layout!(checker, ENetProtocolSendReliable { header, dataLength });
+    layout!(checker, ENetProtocolSendUnreliable { header, unreliableSequenceNumber, dataLength });
+    layout!(checker, ENetProtocolSendUnsequenced { header, unsequencedGroup, dataLength });
+    layout!(checker, ENetProtocolSendFragment {
+        header,
+        startSequenceNumber,
+        dataLength,
+        fragmentCount,
+        fragmentNumber,
+        totalLength,
+        fragmentOffset,
+    });
+    layout!(checker, ENetProtocol {
+        header,
+        acknowledge,
+        connect,
+        verifyConnect,
+        disconnect,
+        ping,
+        sendReliable,
+        sendUnreliable,
+        sendUnsequenced,
+        sendFragment,
+        bandwidthLimit,
+        throttleConfigure,
+    });
+
+    checker.finish();
+}
+
+#[test]
+fn enet() {
+    let mut checker = Checker::new();
+
+    layout!(checker, ENetSocketType);
+    layout!(checker, ENetSocketOption);
+    layout!(checker, ENetSocketShutdown);
+    layout!(checker, ENetPeerState);
+    layout!(checker, ENetEventType);
+
+    layout!(checker, ENetAddress { host, port });
+    layout!(checker, ENetPacket { referenceCount, flag as "flags", data, dataLength, freeCallback, userData });
+    layout!(checker, ENetAcknowledgement { acknowledgementList, sentTime, command });
+    layout!(checker, ENetOutgoingCommand {
+        outgoingCommandList,
+        reliableSequenceNumber,
+        unreliableSequenceNumber,
+        sentTime,
+        roundTripTimeout,
+        roundTripTimeoutLimit,
+        fragmentOffset,
+        fragmentLength,
+        sendAttempts,
+        command,
+        packet,
+    });
+    layout!(checker, ENetIncomingCommand {
+        incomingCommandList,
+        reliableSequenceNumber,
+        unreliableSequenceNumber,
+        command,
+        fragmentCount,
+        fragmentsRemaining,
+        fragments,
+        packet,
+    });
+    layout!(checker, ENetChannel {
+        outgoingReliableSequenceNumber,
+        outgoingUnreliableSequenceNumber,
+        usedReliableWindows,
+        reliableWindows,
+        incomingReliableSequenceNumber,
+        incomingUnreliableSequenceNumber,
+        incomingReliableCommands,
+        incomingUnreliableCommands,
+    });
+    layout!(checker, ENetPeer {
+        dispatchList,
+        host,
+        outgoingPeerID,
+        incomingPeerID,
+        connectID,
+        outgoingSessionID,
+        incomingSessionID,
+        address,
+        data,
+        state,
+        channels,
+        channelCount,
+        incomingBandwidth,
+        outgoingBandwidth,
+        incomingBandwidthThrottleEpoch,
+        outgoingBandwidthThrottleEpoch,
+        incomingDataTotal,
+        outgoingDataTotal,
+        lastSendTime,
+        lastReceiveTime,
+        nextTimeout,
+        earliestTimeout,
+        packetLossEpoch,
+        packetsSent,
+        packetsLost,
+        packetLoss,
+        packetLossVariance,
+        packetThrottle,
+        packetThrottleLimit,
+        packetThrottleCounter,
+        packetThrottleEpoch,
+        packetThrottleAcceleration,
+        packetThrottleDeceleration,
+        packetThrottleInterval,
+        pingInterval,
+        timeoutLimit,
+        timeoutMinimum,
+        timeoutMaximum,
+        lastRoundTripTime,
+        lowestRoundTripTime,
+        lastRoundTripTimeVariance,
+        highestRoundTripTimeVariance,
+        roundTripTime,
+        roundTripTimeVariance,
+        mtu,
+        windowSize,
+        reliableDataInTransit,
+        outgoingReliableSequenceNumber,
+        acknowledgements,
+        sentReliableCommands,
+        sentUnreliableCommands,
+        outgoingCommands,
+        dispatchedCommands,
+        flags,
+        reserved,
+        incomingUnsequencedGroup,
+        outgoingUnsequencedGroup,
+        unsequencedWindow,
+        eventData,
+        totalWaitingData,
+    });
+    layout!(checker, ENetCompressor { context, compress, decompress, destroy });
+    layout!(checker, ENetHost {
+        socket,
+        address,
+        incomingBandwidth,
+        outgoingBandwidth,
+        bandwidthThrottleEpoch,
+        mtu,
+        randomSeed,
+        recalculateBandwidthLimits,
+        peers,
+        peerCount,
+        channelLimit,
+        serviceTime,
+        dispatchQueue,
+        continueSending,
+        packetSize,
+        headerFlags,
+        commands,
+        commandCount,
+        buffers,
+        bufferCount,
+        checksum,
+        compressor,
+        packetData,
+        receivedAddress,
+        receivedData,
+        receivedDataLength,
+        totalSentData,
+        totalSentPackets,
+        totalReceivedData,
+        totalReceivedPackets,
+        intercept,
+        connectedPeers,
+        bandwidthLimitedPeers,
+        duplicatePeers,
+        maximumPacketSize,
+        maximumWaitingData,
+    });
+    layout!(checker, ENetEvent { type_ as "type", peer, channelID, data, packet });
+
+    checker.finish();
+}
@@ -0,0 +1,170 @@
+/*
+ * packet.rs
+ *
+ * checks who owns a packet as it goes through a pair of hosts over the loopback interface
+ */
+
+use std::net::Ipv4Addr;
+#[cfg(feature = "bytes")]
+use std::sync::Arc;
+
+#[cfg(feature = "bytes")]
+use bytes::Bytes;
+use enet_rs::{
+    enet::{ENetAddress, ENetPacketFlag},
+    Enet,
+    Event,
+    Host,
+    HostBuilder,
+    Packet,
+};
+
+/** a server and a client connected to it, both with a single peer */
+fn connected_pair(enet: &Enet) -> (Host, Host) {
+    let mut server: Host = HostBuilder::new()
+        .bind(ENetAddress::new(Ipv4Addr::LOCALHOST, 0))
+        .build(enet)
+        .unwrap();
+    let mut client: Host = HostBuilder::new().build(enet).unwrap();
+    let address = unsafe { (*server.as_raw()).address };
+    client.connect(&address, 1, 0).unwrap();
+
+    let (mut server_connected, mut client_connected) = (false, false);
+    for _ in 0..1000 {
+        client_connected |= matches!(client.service(1).unwrap(), Some(Event::Connect { .. }));
+        server_connected |= matches!(server.service(1).unwrap(), Some(Event::Connect { .. }));
+        if server_connected && client_connected {
+            return (server, client);
+        }
+    }
+
+    panic!("the hosts did not connect");
+}
+
+/** services both hosts until `receiver` gets a packet */
+fn receive(receiver: &mut Host, sender: &mut Host) -> Packet {
+    for _ in 0..1000 {
+        sender.service(1).unwrap();
+        if let Some(Event::Receive { packet, .. }) = receiver.service(1).unwrap() {
+            return packet;
+        }
+    }
+
+    panic!("no packet was received");
+}
+
+#[test]
+fn send_hands_packet_to_enet() {
+    let enet = Enet::new().unwrap();
+    let (mut server, mut client) = connected_pair(&enet);
+
+    let packet = Packet::reliable(&enet, b"sent").unwrap();
+    let raw = packet.as_raw();
+    client.peer_by_id(0).unwrap().send(0, packet).unwrap();
+    // only the queued command refers to the packet, which it destroys once acknowledged
+    assert_eq!(unsafe { (*raw).referenceCount }, 1);
+
+    let received = receive(&mut server, &mut client);
+    assert_eq!(&*received, b"sent");
+    assert!(received.is_unique());
+}
+
+#[test]
+fn broadcast_hands_packet_to_enet() {
+    let enet = Enet::new().unwrap();
+    let (mut server, mut client) = connected_pair(&enet);
+
+    let packet = Packet::reliable(&enet, b"broadcast").unwrap();
+    let raw = packet.as_raw();
+    server.broadcast(0, packet);
+    assert_eq!(unsafe { (*raw).referenceCount }, 1);
+
+    let received = receive(&mut client, &mut server);
+    assert_eq!(&*received, b"broadcast");
+    assert!(received.is_unique());
+}
+
+#[test]
+fn resize_requires_unique_packet() {
+    let enet = Enet::new().unwrap();
+    let mut packet = Packet::unreliable(&enet, b"data").unwrap();
+    packet.resize(2).unwrap();
+    assert_eq!(&*packet, b"da");
+    packet.resize(8).unwrap();
+    assert_eq!(packet.len(), 8);
+
+    let mut shared = unsafe { Packet::from_raw(packet.as_raw()) }.unwrap();
+    assert!(!shared.is_unique());
+    assert!(shared.resize(4).is_err());
+    drop(packet);
+    assert!(shared.is_unique());
+}
+
+#[test]
+fn from_vec_sends_in_place() {
+    let enet = Enet::new().unwrap();
+    let (mut server, mut client) = connected_pair(&enet);
+
+    let data = b"in place".to_vec();
+    let pointer = data.as_ptr();
+    let mut packet = Packet::from_vec(&enet, data, ENetPacketFlag::ENET_PACKET_FLAG_RELIABLE as u32).unwrap();
+    assert_eq!(packet.as_ptr(), pointer);
+
+    // ENet only changes the length of a packet it did not allocate
+    assert!(packet.resize(packet.len() + 1).is_err());
+    packet.resize(2).unwrap();
+    assert_eq!(&*packet, b"in");
+
+    client.peer_by_id(0).unwrap().send(0, packet).unwrap();
+    assert_eq!(&*receive(&mut server, &mut client), b"in");
+}
+
+/** a buffer telling through `alive` whether it has been dropped */
+#[cfg(feature = "bytes")]
+struct Owner {
+    data: Vec<u8>,
+    _alive: Arc<()>,
+}
+
+#[cfg(feature = "bytes")]
+impl AsRef<[u8]> for Owner {
+    fn as_ref(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn from_bytes_frees_owner_once() {
+    let enet = Enet::new().unwrap();
+    let (mut server, mut client) = connected_pair(&enet);
+
+    let alive = Arc::new(());
+    let data = Bytes::from_owner(Owner { data: b"snapshot".to_vec(), _alive: alive.clone() });
+    let flags = ENetPacketFlag::ENET_PACKET_FLAG_RELIABLE as u32;
+
+    // a packet dropped before sending frees its reference right away
+    drop(Packet::from_bytes(&enet, data.clone(), flags).unwrap());
+    assert_eq!(Arc::strong_count(&alive), 2);
+
+    let packet = Packet::from_bytes(&enet, data, flags).unwrap();
+    client.peer_by_id(0).unwrap().send(0, packet).unwrap();
+    assert_eq!(Arc::strong_count(&alive), 2);
+    assert_eq!(&*receive(&mut server, &mut client), b"snapshot");
+
+    // the packet is destroyed, freeing the owner, once the acknowledgement arrives
+    for _ in 0..1000 {
+        if Arc::strong_count(&alive) == 1 {
+            break;
+        }
+
+        client.service(1).unwrap();
+        server.service(1).unwrap();
+    }
+    assert_eq!(Arc::strong_count(&alive), 1);
+
+    // nothing is freed again when the hosts go
+    drop(client);
+    drop(server);
+    assert_eq!(Arc::strong_count(&alive), 1);
+}
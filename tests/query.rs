@@ -0,0 +1,91 @@
+/*
+ * query.rs
+ *
+ * queries a host through its query responder over the loopback interface
+ */
+
+use std::{
+    convert::TryInto,
+    io::ErrorKind,
+    net::{Ipv4Addr, UdpSocket},
+};
+
+use enet_rs::{
+    enet::ENetAddress,
+    query::{QUERY_CHALLENGE, QUERY_REQUEST, QUERY_RESPONSE, QUERY_RESPONSE_MAX},
+    Enet,
+    Error,
+    Host,
+    HostBuilder,
+    QueryResponder,
+};
+
+/** sends `query` to the host and services it until a reply arrives */
+fn exchange(host: &mut Host, client: &UdpSocket, query: &[u8]) -> Vec<u8> {
+    let port = unsafe { (*host.as_raw()).address.port };
+    client.send_to(query, (Ipv4Addr::LOCALHOST, port)).unwrap();
+
+    let mut reply = [0; QUERY_RESPONSE_MAX];
+    for _ in 0..100 {
+        host.service(10).unwrap();
+        match client.recv(&mut reply) {
+            Ok(length) => return reply[..length].to_vec(),
+            Err(e) if e.kind() == ErrorKind::WouldBlock => continue,
+            Err(e) => panic!("receiving the reply failed: {}", e),
+        }
+    }
+
+    panic!("the host did not reply");
+}
+
+#[test]
+fn query_is_answered_after_challenge() {
+    let enet = Enet::new().unwrap();
+    let mut host: Host = HostBuilder::new()
+        .bind(ENetAddress::new(Ipv4Addr::LOCALHOST, 0))
+        .peer_limit(8)
+        .build(&enet)
+        .unwrap();
+    host.set_query_responder(QueryResponder::new("server", b"\x01\x02\x03").unwrap());
+
+    let client = UdpSocket::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+    client.set_nonblocking(true).unwrap();
+
+    // without a challenge, the reply is smaller than the query
+    let challenge = exchange(&mut host, &client, QUERY_REQUEST);
+    assert_eq!(&challenge[..QUERY_CHALLENGE.len()], QUERY_CHALLENGE);
+    assert_eq!(challenge.len(), QUERY_CHALLENGE.len() + 4);
+    assert!(challenge.len() <= QUERY_REQUEST.len());
+
+    // a wrong challenge is challenged again
+    let mut query = QUERY_REQUEST.to_vec();
+    let wrong = u32::from_le_bytes(challenge[QUERY_CHALLENGE.len()..].try_into().unwrap()).wrapping_add(1);
+    query.extend_from_slice(&wrong.to_le_bytes());
+    assert_eq!(exchange(&mut host, &client, &query), challenge);
+
+    let mut query = QUERY_REQUEST.to_vec();
+    query.extend_from_slice(&challenge[QUERY_CHALLENGE.len()..]);
+    let response = exchange(&mut host, &client, &query);
+
+    let mut expected = QUERY_RESPONSE.to_vec();
+    expected.extend_from_slice(b"server\0");
+    expected.extend_from_slice(&0u32.to_le_bytes());
+    expected.extend_from_slice(&8u32.to_le_bytes());
+    expected.extend_from_slice(&3u16.to_le_bytes());
+    expected.extend_from_slice(b"\x01\x02\x03");
+    assert_eq!(response, expected);
+}
+
+#[test]
+fn response_must_fit_a_datagram() {
+    // header, NUL after the name, connected and peer limit, info length
+    let overhead = QUERY_RESPONSE.len() + 1 + 4 + 4 + 2;
+    let info = vec![0; QUERY_RESPONSE_MAX - overhead - 1];
+
+    let mut responder = QueryResponder::new("a", &info).unwrap();
+    assert!(matches!(responder.set_name("ab"), Err(Error::QueryTooLarge { .. })));
+    assert_eq!(responder.name(), "a");
+    assert!(matches!(responder.set_info(&[0; QUERY_RESPONSE_MAX]), Err(Error::QueryTooLarge { .. })));
+    assert_eq!(responder.info().len(), info.len());
+    assert!(QueryResponder::new("", &[0; QUERY_RESPONSE_MAX]).is_err());
+}
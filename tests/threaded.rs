@@ -0,0 +1,63 @@
+/*
+ * threaded.rs
+ *
+ * drives a threaded server and client over the loopback interface
+ */
+
+use std::{net::Ipv4Addr, time::Duration};
+
+use enet_rs::{
+    enet::{ENetAddress, ENetPacketFlag},
+    Error,
+    HostBuilder,
+    HostHandle,
+    ThreadedConfig,
+    ThreadedEvent,
+    ThreadedHost,
+};
+
+fn next_event(handle: &HostHandle) -> ThreadedEvent {
+    handle.recv_timeout(Duration::from_secs(5)).unwrap().expect("no event within 5 seconds")
+}
+
+#[test]
+fn reused_peer_rejects_stale_handle() {
+    let server = ThreadedHost::spawn(ThreadedConfig::new(
+        HostBuilder::new().bind(ENetAddress::new(Ipv4Addr::LOCALHOST, 0)),
+    ))
+    .unwrap();
+    let client = ThreadedHost::spawn(ThreadedConfig::new(HostBuilder::new())).unwrap();
+    let (server_handle, client_handle) = (server.handle(), client.handle());
+
+    client_handle.connect(server.address(), 1, 0).unwrap();
+    let first = match next_event(&server_handle) {
+        ThreadedEvent::Connect { peer, .. } => peer,
+        event => panic!("expected a connection, got {:?}", event),
+    };
+    assert!(matches!(next_event(&client_handle), ThreadedEvent::Connect { .. }));
+
+    // resets the peer without a disconnect event on the server
+    server_handle.disconnect_now(first, 0).unwrap();
+    assert!(matches!(next_event(&client_handle), ThreadedEvent::Disconnect { .. }));
+
+    client_handle.connect(server.address(), 1, 0).unwrap();
+    let second = match next_event(&server_handle) {
+        ThreadedEvent::Connect { peer, .. } => peer,
+        event => panic!("expected a connection, got {:?}", event),
+    };
+    assert_eq!(second.id(), first.id());
+    assert_ne!(second, first);
+
+    server_handle.disconnect(first, 0).unwrap();
+    assert!(matches!(next_event(&server_handle), ThreadedEvent::Error(Error::InvalidPeer(id)) if id == first.id()));
+    server_handle.disconnect_now(first, 0).unwrap();
+    assert!(matches!(next_event(&server_handle), ThreadedEvent::Error(Error::InvalidPeer(id)) if id == first.id()));
+
+    // the new connection is untouched
+    let flags = ENetPacketFlag::ENET_PACKET_FLAG_RELIABLE as u32;
+    server_handle.send(second, 0, b"still here".to_vec(), flags).unwrap();
+    match next_event(&client_handle) {
+        ThreadedEvent::Receive { data, .. } => assert_eq!(data, b"still here"),
+        event => panic!("expected a packet, got {:?}", event),
+    }
+}